@@ -0,0 +1,138 @@
+use slack_http_types::{
+    bookmarks::{AddResponse, EditResponse, ListResponse, RemoveResponse},
+    conversation,
+    error::Error,
+};
+use url::Url;
+
+use crate::client::AuthClient;
+pub use slack_http_types::bookmarks::{Bookmark, BookmarkEdit, Id, Kind, NewBookmark, NewKind};
+
+const ADD: &str = "https://slack.com/api/bookmarks.add";
+const EDIT: &str = "https://slack.com/api/bookmarks.edit";
+const REMOVE: &str = "https://slack.com/api/bookmarks.remove";
+const LIST: &str = "https://slack.com/api/bookmarks.list";
+
+/// Adds a bookmark to the conversation's bookmark bar
+pub async fn add(
+    auth_client: &AuthClient,
+    conversation_id: &conversation::Id,
+    bookmark: &NewBookmark,
+) -> Result<Bookmark, Error> {
+    let mut query_params = bookmark.query_params();
+
+    query_params.push(("channel_id", conversation_id.as_str()));
+
+    let url = Url::parse_with_params(ADD, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", ADD, res.status());
+
+    let json = res
+        .json::<AddResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        AddResponse::Ok { bookmark } => Ok(*bookmark),
+        AddResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn edit(
+    auth_client: &AuthClient,
+    conversation_id: &conversation::Id,
+    bookmark_id: &Id,
+    changes: &BookmarkEdit,
+) -> Result<Bookmark, Error> {
+    let mut query_params = changes.query_params();
+
+    query_params.push(("channel_id", conversation_id.as_str()));
+    query_params.push(("bookmark_id", bookmark_id.as_str()));
+
+    let url = Url::parse_with_params(EDIT, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", EDIT, res.status());
+
+    let json = res
+        .json::<EditResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        EditResponse::Ok { bookmark } => Ok(*bookmark),
+        EditResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn remove(
+    auth_client: &AuthClient,
+    conversation_id: &conversation::Id,
+    bookmark_id: &Id,
+) -> Result<(), Error> {
+    let url = Url::parse_with_params(
+        REMOVE,
+        &[
+            ("channel_id", conversation_id.as_str()),
+            ("bookmark_id", bookmark_id.as_str()),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", REMOVE, res.status());
+
+    let json = res
+        .json::<RemoveResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoveResponse::Ok { .. } => Ok(()),
+        RemoveResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn list(
+    auth_client: &AuthClient,
+    conversation_id: &conversation::Id,
+) -> Result<Vec<Bookmark>, Error> {
+    let url = Url::parse_with_params(LIST, &[("channel_id", conversation_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", LIST, res.status());
+
+    let json = res
+        .json::<ListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ListResponse::Ok { bookmarks } => Ok(bookmarks),
+        ListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
@@ -8,7 +8,7 @@ use time::OffsetDateTime;
 use url::Url;
 
 use crate::client::AuthClient;
-pub use slack_http_types::chat::{Message, MessageOptions};
+pub use slack_http_types::chat::{Attachment, AttachmentField, Message, MessageOptions};
 
 pub async fn post_message(
     auth_client: &AuthClient,
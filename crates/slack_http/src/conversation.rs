@@ -1,5 +1,9 @@
 use slack_http_types::{
-    conversation::{InviteResponse, KickResponse, MembersResponse, OpenResponse},
+    conversation::{
+        AcceptSharedInviteResponse, ApproveSharedInviteResponse, DeclineSharedInviteResponse,
+        InviteResponse, InviteSharedResponse, KickResponse, ListConnectInvitesResponse,
+        MembersResponse, OpenResponse,
+    },
     error::Error,
     page::{Cursor, Limit, Page},
     team, user,
@@ -7,7 +11,10 @@ use slack_http_types::{
 use url::Url;
 
 use crate::client::AuthClient;
-pub use slack_http_types::conversation::{Conversation, Id, ListOptions};
+pub use slack_http_types::conversation::{
+    AcceptSharedInviteOptions, AcceptedSharedInvite, ConnectInvite, Conversation, Id, InviteId,
+    ListOptions, SharedInvite, SharedInvitee,
+};
 
 pub async fn members(
     auth_client: &AuthClient,
@@ -74,6 +81,7 @@ pub async fn open(auth_client: &AuthClient, user_ids: Vec<user::Id>) -> Result<I
     }
 }
 
+#[allow(clippy::extra_unused_lifetimes)]
 pub async fn invite<'channel_id>(
     auth_client: &AuthClient,
     channel_id: &slack_http_types::conversation::Id,
@@ -194,3 +202,198 @@ pub async fn list(
         }
     }
 }
+
+/// Invites an external user to a Slack Connect channel
+pub async fn invite_shared(
+    auth_client: &AuthClient,
+    conversation_id: &Id,
+    invitee: &SharedInvitee,
+    external_limited: bool,
+) -> Result<SharedInvite, Error> {
+    let invitee = match invitee {
+        SharedInvitee::Email(email) => ("emails", email.as_str()),
+        SharedInvitee::User(user_id) => ("user_ids", user_id.as_str()),
+    };
+
+    // Sent as a form so email addresses don't end up in logged URLs
+    let params = [
+        ("channel", conversation_id.as_str()),
+        invitee,
+        (
+            "external_limited",
+            if external_limited { "true" } else { "false" },
+        ),
+    ];
+
+    let url = Url::parse("https://slack.com/api/conversations.inviteShared")?;
+
+    let res = auth_client
+        .client()
+        .post(url.as_str())
+        .form(&params)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", url, res.status());
+
+    let json = res
+        .json::<InviteSharedResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        InviteSharedResponse::Ok(invite) => Ok(invite),
+        InviteSharedResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Accepts a Slack Connect invite on behalf of the authed team
+pub async fn accept_shared_invite(
+    auth_client: &AuthClient,
+    channel_name: &str,
+    invite_id: &InviteId,
+    opts: &AcceptSharedInviteOptions,
+) -> Result<AcceptedSharedInvite, Error> {
+    let mut query_params = opts.query_params();
+
+    query_params.push(("channel_name", channel_name));
+    query_params.push(("invite_id", invite_id.as_str()));
+
+    let url = Url::parse_with_params(
+        "https://slack.com/api/conversations.acceptSharedInvite",
+        &query_params,
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url.as_str())
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", url, res.status());
+
+    let json = res
+        .json::<AcceptSharedInviteResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        AcceptSharedInviteResponse::Ok(accepted) => Ok(accepted),
+        AcceptSharedInviteResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Approves a Slack Connect invite. `target_team` is only needed for Enterprise
+/// Grid orgs.
+pub async fn approve_shared_invite(
+    auth_client: &AuthClient,
+    invite_id: &InviteId,
+    target_team: Option<&team::Id>,
+) -> Result<(), Error> {
+    let mut query_params = vec![("invite_id", invite_id.as_str())];
+
+    if let Some(target_team) = target_team {
+        query_params.push(("target_team", target_team.0.as_str()));
+    }
+
+    let url = Url::parse_with_params(
+        "https://slack.com/api/conversations.approveSharedInvite",
+        &query_params,
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url.as_str())
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", url, res.status());
+
+    let json = res
+        .json::<ApproveSharedInviteResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ApproveSharedInviteResponse::Ok { .. } => Ok(()),
+        ApproveSharedInviteResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Declines a Slack Connect invite. `target_team` is only needed for Enterprise
+/// Grid orgs.
+pub async fn decline_shared_invite(
+    auth_client: &AuthClient,
+    invite_id: &InviteId,
+    target_team: Option<&team::Id>,
+) -> Result<(), Error> {
+    let mut query_params = vec![("invite_id", invite_id.as_str())];
+
+    if let Some(target_team) = target_team {
+        query_params.push(("target_team", target_team.0.as_str()));
+    }
+
+    let url = Url::parse_with_params(
+        "https://slack.com/api/conversations.declineSharedInvite",
+        &query_params,
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url.as_str())
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", url, res.status());
+
+    let json = res
+        .json::<DeclineSharedInviteResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        DeclineSharedInviteResponse::Ok { .. } => Ok(()),
+        DeclineSharedInviteResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Lists Slack Connect invites that were sent or received by the authed team
+pub async fn list_connect_invites(
+    auth_client: &AuthClient,
+    cursor: &Cursor,
+    limit: &Limit,
+) -> Result<Page<ConnectInvite>, Error> {
+    let url = Url::parse_with_params(
+        "https://slack.com/api/conversations.listConnectInvites",
+        &[
+            ("cursor", cursor.as_str()),
+            ("count", limit.get().to_string().as_str()),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url.as_str())
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", url, res.status());
+
+    let json = res
+        .json::<ListConnectInvitesResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ListConnectInvitesResponse::Ok {
+            invites,
+            response_metadata,
+        } => Ok(Page::new(invites, Cursor::from(response_metadata))),
+        ListConnectInvitesResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
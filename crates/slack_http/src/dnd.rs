@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use crate::client::AuthClient;
+pub use slack_http_types::dnd::{DndStatus, Snooze};
+use slack_http_types::{
+    dnd::{EndDndResponse, EndSnoozeResponse, InfoResponse, SetSnoozeResponse, TeamInfoResponse},
+    error::Error,
+    user,
+};
+use url::Url;
+
+const INFO: &str = "https://slack.com/api/dnd.info";
+const TEAM_INFO: &str = "https://slack.com/api/dnd.teamInfo";
+const SET_SNOOZE: &str = "https://slack.com/api/dnd.setSnooze";
+const END_SNOOZE: &str = "https://slack.com/api/dnd.endSnooze";
+const END_DND: &str = "https://slack.com/api/dnd.endDnd";
+
+/// Gets a user's DND status. Defaults to the authed user if `user_id` is
+/// `None`.
+pub async fn info(
+    auth_client: &AuthClient,
+    user_id: Option<&user::Id>,
+) -> Result<DndStatus, Error> {
+    let mut query_params = Vec::new();
+
+    if let Some(user_id) = user_id {
+        query_params.push(("user", user_id.as_str()));
+    }
+
+    let url = Url::parse_with_params(INFO, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", INFO, res.status());
+
+    let json = res
+        .json::<InfoResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        InfoResponse::Ok(status) => Ok(status),
+        InfoResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Gets the DND status of up to 50 users at once
+pub async fn team_info(
+    auth_client: &AuthClient,
+    user_ids: &[user::Id],
+) -> Result<HashMap<user::Id, DndStatus>, Error> {
+    let url = Url::parse_with_params(
+        TEAM_INFO,
+        &[(
+            "users",
+            user_ids
+                .iter()
+                .map(|uid| uid.as_str())
+                .collect::<Vec<&str>>()
+                .join(","),
+        )],
+    )?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", TEAM_INFO, res.status());
+
+    let json = res
+        .json::<TeamInfoResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        TeamInfoResponse::Ok { users } => Ok(users),
+        TeamInfoResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Turns on DND for the authed user for `num_minutes`
+pub async fn set_snooze(auth_client: &AuthClient, num_minutes: u32) -> Result<Snooze, Error> {
+    let num_minutes = num_minutes.to_string();
+    let url = Url::parse_with_params(SET_SNOOZE, &[("num_minutes", num_minutes.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", SET_SNOOZE, res.status());
+
+    let json = res
+        .json::<SetSnoozeResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        SetSnoozeResponse::Ok(snooze) => Ok(snooze),
+        SetSnoozeResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Ends the authed user's snooze early
+pub async fn end_snooze(auth_client: &AuthClient) -> Result<DndStatus, Error> {
+    let url = Url::parse(END_SNOOZE)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", END_SNOOZE, res.status());
+
+    let json = res
+        .json::<EndSnoozeResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        EndSnoozeResponse::Ok(status) => Ok(status),
+        EndSnoozeResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Ends the authed user's current DND session, scheduled or snoozed
+pub async fn end_dnd(auth_client: &AuthClient) -> Result<(), Error> {
+    let url = Url::parse(END_DND)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", END_DND, res.status());
+
+    let json = res
+        .json::<EndDndResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        EndDndResponse::Ok { .. } => Ok(()),
+        EndDndResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
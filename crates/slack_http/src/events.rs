@@ -0,0 +1,329 @@
+use std::{future::Future, pin::Pin};
+
+use http::{header::CONTENT_TYPE, HeaderMap, HeaderValue, Response, StatusCode};
+use serde_json::Value;
+use slack_http_types::team;
+use time::OffsetDateTime;
+
+use crate::Verifier;
+
+pub use slack_http_types::events::{
+    AppHomeOpened, AppMention, AppRateLimited, Authorization, ChangedMessage, ChannelCreated,
+    CreatedChannel, Edited, Envelope, Event, EventCallback, EventId, MemberJoinedChannel,
+    MemberLeftChannel, Message, MessageChanged, MessageDeleted, MessageEvent, MessageSubtype,
+    ReactionAdded, ReactionItem, ReactionRemoved, TeamJoin, UrlVerification, UserChange,
+};
+
+const RETRY_NUM_HEADER: &str = "x-slack-retry-num";
+const RETRY_REASON_HEADER: &str = "x-slack-retry-reason";
+
+type HandlerFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type Handler<T> = Box<dyn Fn(Context, T) -> HandlerFuture + Send + Sync>;
+
+/// Everything in an `event_callback` besides the event itself
+#[derive(Debug)]
+pub struct Context {
+    pub team_id: team::Id,
+    pub api_app_id: String,
+    pub event_id: EventId,
+    pub event_time: OffsetDateTime,
+    pub authorizations: Vec<Authorization>,
+    pub is_ext_shared_channel: bool,
+    /// How many times Slack has sent this event before. `None` on the first
+    /// try.
+    pub retry_num: Option<u32>,
+    /// Why Slack is retrying, e.g. `http_timeout`
+    pub retry_reason: Option<String>,
+}
+
+/// Receives requests from the Events API and hands each event to the handler
+/// registered for its type.
+///
+/// Slack expects a response within 3 seconds, and the response is only sent
+/// after the handler finishes. Handlers doing anything slow should spawn it
+/// and return.
+pub struct Dispatcher {
+    verifier: Verifier,
+    app_home_opened: Option<Handler<AppHomeOpened>>,
+    app_mention: Option<Handler<AppMention>>,
+    channel_created: Option<Handler<ChannelCreated>>,
+    member_joined_channel: Option<Handler<MemberJoinedChannel>>,
+    member_left_channel: Option<Handler<MemberLeftChannel>>,
+    message: Option<Handler<MessageEvent>>,
+    reaction_added: Option<Handler<ReactionAdded>>,
+    reaction_removed: Option<Handler<ReactionRemoved>>,
+    team_join: Option<Handler<TeamJoin>>,
+    user_change: Option<Handler<UserChange>>,
+    unknown: Option<Handler<Value>>,
+    app_rate_limited: Option<Box<dyn Fn(AppRateLimited) -> HandlerFuture + Send + Sync>>,
+}
+
+impl Dispatcher {
+    pub fn new(signing_secret: &[u8]) -> Self {
+        Self::with_verifier(Verifier::new(signing_secret))
+    }
+
+    /// Verifies requests with a `Verifier` set up with another tolerance or
+    /// clock
+    pub fn with_verifier(verifier: Verifier) -> Self {
+        Self {
+            verifier,
+            app_home_opened: None,
+            app_mention: None,
+            channel_created: None,
+            member_joined_channel: None,
+            member_left_channel: None,
+            message: None,
+            reaction_added: None,
+            reaction_removed: None,
+            team_join: None,
+            user_change: None,
+            unknown: None,
+            app_rate_limited: None,
+        }
+    }
+
+    pub fn on_app_home_opened<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, AppHomeOpened) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            app_home_opened: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_app_mention<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, AppMention) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            app_mention: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_channel_created<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, ChannelCreated) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            channel_created: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_member_joined_channel<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, MemberJoinedChannel) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            member_joined_channel: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_member_left_channel<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, MemberLeftChannel) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            member_left_channel: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    /// Handles every `message` event, including edits and deletions
+    pub fn on_message<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, MessageEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            message: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_reaction_added<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, ReactionAdded) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            reaction_added: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_reaction_removed<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, ReactionRemoved) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            reaction_removed: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_team_join<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, TeamJoin) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            team_join: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_user_change<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, UserChange) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            user_change: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    /// Handles events that don't have a type in this crate, as they were sent
+    pub fn on_unknown<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            unknown: Some(boxed(handler)),
+            ..self
+        }
+    }
+
+    pub fn on_app_rate_limited<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(AppRateLimited) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            app_rate_limited: Some(Box::new(move |limited| Box::pin(handler(limited)))),
+            ..self
+        }
+    }
+
+    /// Verifies a request from the Events API, answers it if it's a URL
+    /// verification, and otherwise runs the handler for its event. Events
+    /// without a handler and verified bodies that can't be deserialized are
+    /// acknowledged and dropped.
+    pub async fn dispatch(&self, headers: &HeaderMap, body: &[u8]) -> Response<String> {
+        if let Err(e) = self.verifier.verify_request(headers, body) {
+            tracing::warn!("rejected event request: {}", e);
+            return respond(StatusCode::UNAUTHORIZED, String::new());
+        }
+
+        let envelope = match serde_json::from_slice::<Envelope>(body) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                // Anything but a 200 makes Slack retry, and eventually turn
+                // off the subscription, over a body that will never parse.
+                tracing::warn!("dropped event request that failed to deserialize: {}", e);
+                return respond(StatusCode::OK, String::new());
+            }
+        };
+
+        match envelope {
+            Envelope::UrlVerification(verification) => {
+                respond(StatusCode::OK, verification.challenge)
+            }
+            Envelope::EventCallback(callback) => {
+                self.handle_event(headers, *callback).await;
+                respond(StatusCode::OK, String::new())
+            }
+            Envelope::AppRateLimited(limited) => {
+                if let Some(handler) = &self.app_rate_limited {
+                    handler(limited).await;
+                }
+
+                respond(StatusCode::OK, String::new())
+            }
+        }
+    }
+
+    async fn handle_event(&self, headers: &HeaderMap, callback: EventCallback) {
+        let EventCallback {
+            team_id,
+            api_app_id,
+            event,
+            event_id,
+            event_time,
+            authorizations,
+            is_ext_shared_channel,
+            ..
+        } = callback;
+
+        let context = Context {
+            team_id,
+            api_app_id,
+            event_id,
+            event_time,
+            authorizations,
+            is_ext_shared_channel,
+            retry_num: header_str(headers, RETRY_NUM_HEADER).and_then(|num| num.parse().ok()),
+            retry_reason: header_str(headers, RETRY_REASON_HEADER).map(str::to_string),
+        };
+
+        tracing::info!("{} event {}", event.kind(), context.event_id.as_str());
+
+        match event {
+            Event::AppHomeOpened(event) => run(&self.app_home_opened, context, event).await,
+            Event::AppMention(event) => run(&self.app_mention, context, event).await,
+            Event::ChannelCreated(event) => run(&self.channel_created, context, event).await,
+            Event::MemberJoinedChannel(event) => {
+                run(&self.member_joined_channel, context, event).await
+            }
+            Event::MemberLeftChannel(event) => run(&self.member_left_channel, context, event).await,
+            Event::Message(event) => run(&self.message, context, event).await,
+            Event::ReactionAdded(event) => run(&self.reaction_added, context, event).await,
+            Event::ReactionRemoved(event) => run(&self.reaction_removed, context, event).await,
+            Event::TeamJoin(event) => run(&self.team_join, context, event).await,
+            Event::UserChange(event) => run(&self.user_change, context, event).await,
+            Event::Unknown(event) => run(&self.unknown, context, event).await,
+        }
+    }
+}
+
+fn boxed<T, F, Fut>(handler: F) -> Handler<T>
+where
+    F: Fn(Context, T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    Box::new(move |context, event| Box::pin(handler(context, event)))
+}
+
+async fn run<T>(handler: &Option<Handler<T>>, context: Context, event: T) {
+    if let Some(handler) = handler {
+        handler(context, event).await
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn respond(status: StatusCode, body: String) -> Response<String> {
+    let mut res = Response::new(body);
+    *res.status_mut() = status;
+
+    if !res.body().is_empty() {
+        res.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+    }
+
+    res
+}
@@ -0,0 +1,537 @@
+use reqwest::{
+    header::CONTENT_LENGTH,
+    multipart::{Form, Part},
+    Body,
+};
+use slack_http_types::{
+    conversation,
+    error::Error,
+    files::{
+        CompleteUploadExternalResponse, DeleteResponse, GetUploadUrlExternalResponse, InfoResponse,
+        ListResponse, RemoteAddResponse, RemoteInfoResponse, RemoteListResponse,
+        RemoteRemoveResponse, RemoteShareResponse, RemoteUpdateResponse, RevokePublicUrlResponse,
+        SharedPublicUrlResponse,
+    },
+    page::{Cursor, NumberedPage, Page},
+};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+use url::Url;
+
+use crate::client::AuthClient;
+pub use slack_http_types::files::{
+    CompleteUploadOptions, ExternalId, File, FileUpload, Id, ListOptions, NewRemoteFile,
+    PreviewImage, RemoteFileUpdate, RemoteListOptions, TypeFilter, UploadUrl,
+};
+
+const GET_UPLOAD_URL_EXTERNAL: &str = "https://slack.com/api/files.getUploadURLExternal";
+const COMPLETE_UPLOAD_EXTERNAL: &str = "https://slack.com/api/files.completeUploadExternal";
+const INFO: &str = "https://slack.com/api/files.info";
+const LIST: &str = "https://slack.com/api/files.list";
+const DELETE: &str = "https://slack.com/api/files.delete";
+const SHARED_PUBLIC_URL: &str = "https://slack.com/api/files.sharedPublicURL";
+const REVOKE_PUBLIC_URL: &str = "https://slack.com/api/files.revokePublicURL";
+const REMOTE_ADD: &str = "https://slack.com/api/files.remote.add";
+const REMOTE_INFO: &str = "https://slack.com/api/files.remote.info";
+const REMOTE_LIST: &str = "https://slack.com/api/files.remote.list";
+const REMOTE_REMOVE: &str = "https://slack.com/api/files.remote.remove";
+const REMOTE_SHARE: &str = "https://slack.com/api/files.remote.share";
+const REMOTE_UPDATE: &str = "https://slack.com/api/files.remote.update";
+
+/// Reserves a file and gets the URL its contents should be sent to. `length`
+/// is the file's size in bytes.
+pub async fn get_upload_url_external(
+    auth_client: &AuthClient,
+    filename: &str,
+    length: u64,
+    alt_text: Option<&str>,
+) -> Result<UploadUrl, Error> {
+    let length = length.to_string();
+    let mut query_params = vec![("filename", filename), ("length", length.as_str())];
+
+    if let Some(alt_text) = alt_text {
+        query_params.push(("alt_txt", alt_text));
+    }
+
+    let url = Url::parse_with_params(GET_UPLOAD_URL_EXTERNAL, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", GET_UPLOAD_URL_EXTERNAL, res.status());
+
+    let json = res
+        .json::<GetUploadUrlExternalResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        GetUploadUrlExternalResponse::Ok(upload_url) => Ok(upload_url),
+        GetUploadUrlExternalResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Streams a file's contents to its upload URL. `length` has to match what
+/// was given to `get_upload_url_external`.
+pub async fn upload_to_url<R>(
+    auth_client: &AuthClient,
+    upload_url: &UploadUrl,
+    contents: R,
+    length: u64,
+) -> Result<(), Error>
+where
+    R: AsyncRead + Send + Sync + 'static,
+{
+    let body = Body::wrap_stream(ReaderStream::new(contents));
+
+    let res = auth_client
+        .client()
+        .post(upload_url.upload_url.as_str())
+        .header(CONTENT_LENGTH, length)
+        .body(body)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", upload_url.upload_url, res.status());
+
+    res.error_for_status().map_err(Error::Request)?;
+
+    Ok(())
+}
+
+/// Finishes uploading files that were sent to their upload URLs, and shares
+/// them if a channel is given.
+pub async fn complete_upload_external(
+    auth_client: &AuthClient,
+    files: &[FileUpload],
+    opts: &CompleteUploadOptions,
+) -> Result<Vec<FileUpload>, Error> {
+    let mut query_params = opts.query_params();
+
+    query_params.push(("files", serde_json::to_string(files)?));
+
+    let url = Url::parse_with_params(COMPLETE_UPLOAD_EXTERNAL, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", COMPLETE_UPLOAD_EXTERNAL, res.status());
+
+    let json = res
+        .json::<CompleteUploadExternalResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        CompleteUploadExternalResponse::Ok { files } => Ok(files),
+        CompleteUploadExternalResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Uploads a single file in one go and returns its ID. Use the functions above
+/// directly to upload several files and share them in one message.
+pub async fn upload<R>(
+    auth_client: &AuthClient,
+    filename: &str,
+    contents: R,
+    length: u64,
+    alt_text: Option<&str>,
+    opts: &CompleteUploadOptions,
+) -> Result<Id, Error>
+where
+    R: AsyncRead + Send + Sync + 'static,
+{
+    let upload_url = get_upload_url_external(auth_client, filename, length, alt_text).await?;
+
+    upload_to_url(auth_client, &upload_url, contents, length).await?;
+
+    let file = FileUpload::new(upload_url.file_id.clone()).set_title(filename.to_string());
+
+    complete_upload_external(auth_client, &[file], opts).await?;
+
+    Ok(upload_url.file_id)
+}
+
+pub async fn info(auth_client: &AuthClient, file_id: &Id) -> Result<File, Error> {
+    let url = Url::parse_with_params(INFO, &[("file", file_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", INFO, res.status());
+
+    let json = res
+        .json::<InfoResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        InfoResponse::Ok { file } => Ok(*file),
+        InfoResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Lists files in the team. `page` starts at 1.
+pub async fn list(
+    auth_client: &AuthClient,
+    page: u32,
+    opts: &ListOptions,
+) -> Result<NumberedPage<File>, Error> {
+    let mut query_params = opts.query_params();
+
+    query_params.push(("page", page.to_string()));
+
+    let url = Url::parse_with_params(LIST, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", LIST, res.status());
+
+    let json = res
+        .json::<ListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ListResponse::Ok { files, paging } => Ok(NumberedPage::new(files, paging)),
+        ListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn delete(auth_client: &AuthClient, file_id: &Id) -> Result<(), Error> {
+    let url = Url::parse_with_params(DELETE, &[("file", file_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", DELETE, res.status());
+
+    let json = res
+        .json::<DeleteResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        DeleteResponse::Ok { .. } => Ok(()),
+        DeleteResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Makes a file publicly available. Needs a user token.
+pub async fn shared_public_url(auth_client: &AuthClient, file_id: &Id) -> Result<File, Error> {
+    let url = Url::parse_with_params(SHARED_PUBLIC_URL, &[("file", file_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", SHARED_PUBLIC_URL, res.status());
+
+    let json = res
+        .json::<SharedPublicUrlResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        SharedPublicUrlResponse::Ok { file } => Ok(*file),
+        SharedPublicUrlResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Stops a file from being publicly available. Needs a user token.
+pub async fn revoke_public_url(auth_client: &AuthClient, file_id: &Id) -> Result<File, Error> {
+    let url = Url::parse_with_params(REVOKE_PUBLIC_URL, &[("file", file_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", REVOKE_PUBLIC_URL, res.status());
+
+    let json = res
+        .json::<RevokePublicUrlResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RevokePublicUrlResponse::Ok { file } => Ok(*file),
+        RevokePublicUrlResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Streams a file's `url_private` (or `url_private_download`) into `writer`
+/// and returns how many bytes were written.
+///
+/// The request carries the client's token, so URLs that aren't `https` on
+/// Slack's own hosts, like the `url_private` of an external file, are refused
+/// with `Error::NotSlackUrl` without being requested.
+pub async fn download<W>(auth_client: &AuthClient, url: &Url, writer: &mut W) -> Result<u64, Error>
+where
+    W: AsyncWrite + Unpin,
+{
+    if !is_slack_url(url) {
+        return Err(Error::NotSlackUrl(url.clone()));
+    }
+
+    let mut res = auth_client
+        .client()
+        .get(url.as_str())
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", url, res.status());
+
+    res = res.error_for_status().map_err(Error::Request)?;
+
+    let mut written = 0;
+
+    while let Some(chunk) = res.chunk().await.map_err(Error::Request)? {
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+
+    writer.flush().await?;
+
+    Ok(written)
+}
+
+/// Adds a file hosted outside of Slack. Sent as a multipart form so a
+/// preview image can go along with it.
+pub async fn remote_add(auth_client: &AuthClient, file: NewRemoteFile) -> Result<File, Error> {
+    let url = Url::parse(REMOTE_ADD)?;
+
+    let form = remote_form(file.text_params(), file.preview_image);
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", REMOTE_ADD, res.status());
+
+    let json = res
+        .json::<RemoteAddResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoteAddResponse::Ok { file } => Ok(*file),
+        RemoteAddResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn remote_info(
+    auth_client: &AuthClient,
+    external_id: &ExternalId,
+) -> Result<File, Error> {
+    let url = Url::parse_with_params(REMOTE_INFO, &[("external_id", external_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", REMOTE_INFO, res.status());
+
+    let json = res
+        .json::<RemoteInfoResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoteInfoResponse::Ok { file } => Ok(*file),
+        RemoteInfoResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn remote_list(
+    auth_client: &AuthClient,
+    cursor: &Cursor,
+    options: &RemoteListOptions,
+) -> Result<Page<File>, Error> {
+    let mut query_params = options.query_params();
+    query_params.push(("cursor", cursor.as_str().to_string()));
+
+    let url = Url::parse_with_params(REMOTE_LIST, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", REMOTE_LIST, res.status());
+
+    let json = res
+        .json::<RemoteListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoteListResponse::Ok {
+            files,
+            response_metadata,
+        } => Ok(Page::new(files, Cursor::from(response_metadata))),
+        RemoteListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Removes a remote file from Slack. The file itself is left untouched.
+pub async fn remote_remove(
+    auth_client: &AuthClient,
+    external_id: &ExternalId,
+) -> Result<(), Error> {
+    let url = Url::parse_with_params(REMOTE_REMOVE, &[("external_id", external_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", REMOTE_REMOVE, res.status());
+
+    let json = res
+        .json::<RemoteRemoveResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoteRemoveResponse::Ok { .. } => Ok(()),
+        RemoteRemoveResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn remote_share(
+    auth_client: &AuthClient,
+    external_id: &ExternalId,
+    channels: &[conversation::Id],
+) -> Result<File, Error> {
+    let channels = channels
+        .iter()
+        .map(|channel| channel.0.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let url = Url::parse_with_params(
+        REMOTE_SHARE,
+        &[
+            ("external_id", external_id.as_str()),
+            ("channels", channels.as_str()),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", REMOTE_SHARE, res.status());
+
+    let json = res
+        .json::<RemoteShareResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoteShareResponse::Ok { file } => Ok(*file),
+        RemoteShareResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn remote_update(
+    auth_client: &AuthClient,
+    external_id: &ExternalId,
+    update: RemoteFileUpdate,
+) -> Result<File, Error> {
+    let url = Url::parse(REMOTE_UPDATE)?;
+
+    let mut text_params = update.text_params();
+    text_params.push(("external_id", external_id.0.clone()));
+
+    let form = remote_form(text_params, update.preview_image);
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", REMOTE_UPDATE, res.status());
+
+    let json = res
+        .json::<RemoteUpdateResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoteUpdateResponse::Ok { file } => Ok(*file),
+        RemoteUpdateResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+fn remote_form(
+    text_params: Vec<(&'static str, String)>,
+    preview_image: Option<PreviewImage>,
+) -> Form {
+    let mut form = text_params
+        .into_iter()
+        .fold(Form::new(), |form, (name, value)| form.text(name, value));
+
+    if let Some(preview_image) = preview_image {
+        form = form.part(
+            "preview_image",
+            Part::bytes(preview_image.image).file_name(preview_image.file_name),
+        );
+    }
+
+    form
+}
+
+fn is_slack_url(url: &Url) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+
+    url.scheme() == "https" && (host == "slack.com" || host.ends_with(".slack.com"))
+}
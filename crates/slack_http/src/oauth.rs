@@ -17,6 +17,7 @@ const V2_ACCESS: &str = "https://slack.com/api/oauth.v2.access";
 ////////////////////////////////////////////////////////////////////////////////
 /// Functions
 
+#[allow(clippy::empty_line_after_doc_comments)]
 pub async fn v2_refresh_access(
     basic_client: &BasicClient,
     client_id: &str,
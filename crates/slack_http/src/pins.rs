@@ -0,0 +1,113 @@
+use slack_http_types::{
+    conversation,
+    error::Error,
+    pins::{AddResponse, ListResponse, RemoveResponse},
+};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::client::AuthClient;
+pub use slack_http_types::pins::{PinnedFile, PinnedItem, PinnedMessage};
+
+const ADD: &str = "https://slack.com/api/pins.add";
+const REMOVE: &str = "https://slack.com/api/pins.remove";
+const LIST: &str = "https://slack.com/api/pins.list";
+
+/// Pins a message to the conversation
+pub async fn add(
+    auth_client: &AuthClient,
+    conversation_id: &conversation::Id,
+    timestamp: &OffsetDateTime,
+) -> Result<(), Error> {
+    let timestamp = slack_http_types::offset_date_time_to_unix_ts_with_nano(timestamp);
+
+    let url = Url::parse_with_params(
+        ADD,
+        &[
+            ("channel", conversation_id.as_str()),
+            ("timestamp", timestamp.as_str()),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", ADD, res.status());
+
+    let json = res
+        .json::<AddResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        AddResponse::Ok { .. } => Ok(()),
+        AddResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Unpins a message from the conversation
+pub async fn remove(
+    auth_client: &AuthClient,
+    conversation_id: &conversation::Id,
+    timestamp: &OffsetDateTime,
+) -> Result<(), Error> {
+    let timestamp = slack_http_types::offset_date_time_to_unix_ts_with_nano(timestamp);
+
+    let url = Url::parse_with_params(
+        REMOVE,
+        &[
+            ("channel", conversation_id.as_str()),
+            ("timestamp", timestamp.as_str()),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", REMOVE, res.status());
+
+    let json = res
+        .json::<RemoveResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoveResponse::Ok { .. } => Ok(()),
+        RemoveResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Lists everything pinned to the conversation
+pub async fn list(
+    auth_client: &AuthClient,
+    conversation_id: &conversation::Id,
+) -> Result<Vec<PinnedItem>, Error> {
+    let url = Url::parse_with_params(LIST, &[("channel", conversation_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", LIST, res.status());
+
+    let json = res
+        .json::<ListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ListResponse::Ok { items } => Ok(items),
+        ListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
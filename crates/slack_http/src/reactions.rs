@@ -0,0 +1,158 @@
+use slack_http_types::{
+    conversation,
+    error::Error,
+    page::{Cursor, Limit, Page},
+    reactions::{AddResponse, GetResponse, ListResponse, RemoveResponse},
+    user,
+};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::client::AuthClient;
+pub use slack_http_types::reactions::{
+    Item, Name, ReactedFile, ReactedItem, ReactedMessage, Reaction,
+};
+
+const ADD: &str = "https://slack.com/api/reactions.add";
+const REMOVE: &str = "https://slack.com/api/reactions.remove";
+const GET: &str = "https://slack.com/api/reactions.get";
+const LIST: &str = "https://slack.com/api/reactions.list";
+
+/// Reacts to a message with `name`
+pub async fn add(
+    auth_client: &AuthClient,
+    conversation_id: &conversation::Id,
+    timestamp: &OffsetDateTime,
+    name: &Name,
+) -> Result<(), Error> {
+    let timestamp = slack_http_types::offset_date_time_to_unix_ts_with_nano(timestamp);
+
+    let url = Url::parse_with_params(
+        ADD,
+        &[
+            ("channel", conversation_id.as_str()),
+            ("timestamp", timestamp.as_str()),
+            ("name", name.as_str()),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", ADD, res.status());
+
+    let json = res
+        .json::<AddResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        AddResponse::Ok { .. } => Ok(()),
+        AddResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Removes the authed user's `name` reaction from a message or file
+pub async fn remove(auth_client: &AuthClient, item: &Item, name: &Name) -> Result<(), Error> {
+    let mut query_params = item.query_params();
+
+    query_params.push(("name", name.0.clone()));
+
+    let url = Url::parse_with_params(REMOVE, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", REMOVE, res.status());
+
+    let json = res
+        .json::<RemoveResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        RemoveResponse::Ok { .. } => Ok(()),
+        RemoveResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Gets every reaction on a message or file
+pub async fn get(auth_client: &AuthClient, item: &Item) -> Result<ReactedItem, Error> {
+    let mut query_params = item.query_params();
+
+    query_params.push(("full", "true".to_string()));
+
+    let url = Url::parse_with_params(GET, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", GET, res.status());
+
+    let json = res
+        .json::<GetResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        GetResponse::Ok(item) => Ok(item),
+        GetResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Lists items reacted to by a user. Defaults to the authed user if `user_id`
+/// is `None`.
+pub async fn list(
+    auth_client: &AuthClient,
+    user_id: Option<&user::Id>,
+    cursor: &Cursor,
+    limit: &Limit,
+) -> Result<Page<ReactedItem>, Error> {
+    let limit = limit.get().to_string();
+
+    let mut query_params = vec![
+        ("cursor", cursor.as_str()),
+        ("limit", limit.as_str()),
+        ("full", "true"),
+    ];
+
+    if let Some(user_id) = user_id {
+        query_params.push(("user", user_id.as_str()));
+    }
+
+    let url = Url::parse_with_params(LIST, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", LIST, res.status());
+
+    let json = res
+        .json::<ListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ListResponse::Ok {
+            items,
+            response_metadata,
+        } => Ok(Page::new(items, Cursor::from(response_metadata))),
+        ListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
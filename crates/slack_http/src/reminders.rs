@@ -0,0 +1,132 @@
+use slack_http_types::{
+    error::Error,
+    reminders::{AddResponse, CompleteResponse, DeleteResponse, InfoResponse, ListResponse},
+};
+use url::Url;
+
+use crate::client::AuthClient;
+pub use slack_http_types::reminders::{Id, NewReminder, Recurrence, Reminder, When};
+
+const ADD: &str = "https://slack.com/api/reminders.add";
+const COMPLETE: &str = "https://slack.com/api/reminders.complete";
+const DELETE: &str = "https://slack.com/api/reminders.delete";
+const INFO: &str = "https://slack.com/api/reminders.info";
+const LIST: &str = "https://slack.com/api/reminders.list";
+
+/// Creates a reminder. Needs a user token.
+pub async fn add(auth_client: &AuthClient, reminder: &NewReminder) -> Result<Reminder, Error> {
+    let url = Url::parse_with_params(ADD, &reminder.query_params()?)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", ADD, res.status());
+
+    let json = res
+        .json::<AddResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        AddResponse::Ok { reminder } => Ok(reminder),
+        AddResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Marks a reminder as complete. Recurring reminders can't be completed.
+pub async fn complete(auth_client: &AuthClient, reminder_id: &Id) -> Result<(), Error> {
+    let url = Url::parse_with_params(COMPLETE, &[("reminder", reminder_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", COMPLETE, res.status());
+
+    let json = res
+        .json::<CompleteResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        CompleteResponse::Ok { .. } => Ok(()),
+        CompleteResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn delete(auth_client: &AuthClient, reminder_id: &Id) -> Result<(), Error> {
+    let url = Url::parse_with_params(DELETE, &[("reminder", reminder_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", DELETE, res.status());
+
+    let json = res
+        .json::<DeleteResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        DeleteResponse::Ok { .. } => Ok(()),
+        DeleteResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn info(auth_client: &AuthClient, reminder_id: &Id) -> Result<Reminder, Error> {
+    let url = Url::parse_with_params(INFO, &[("reminder", reminder_id.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", INFO, res.status());
+
+    let json = res
+        .json::<InfoResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        InfoResponse::Ok { reminder } => Ok(reminder),
+        InfoResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Lists every reminder created by or for the authed user
+pub async fn list(auth_client: &AuthClient) -> Result<Vec<Reminder>, Error> {
+    let url = Url::parse(LIST)?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", LIST, res.status());
+
+    let json = res
+        .json::<ListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ListResponse::Ok { reminders } => Ok(reminders),
+        ListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
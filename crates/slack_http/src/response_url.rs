@@ -0,0 +1,120 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use slack_http_types::{error::Error, response_url::PostResponse};
+use time::{Duration, OffsetDateTime};
+use url::Url;
+
+use crate::client::BasicClient;
+pub use slack_http_types::response_url::ResponseMessage;
+
+/// How many times a `response_url` can be used
+pub const MAX_USES: u8 = 5;
+/// How long a `response_url` can be used for after it was received
+pub const VALID_FOR: Duration = Duration::minutes(30);
+
+/// A `response_url` along with how much of it is left. Slack only accepts 5
+/// messages within 30 minutes of the request it came with, so `send` and
+/// `delete` refuse with `Error::Slack("used_url")` or
+/// `Error::Slack("expired_url")` before contacting Slack, the same errors Slack
+/// would answer with.
+#[derive(Debug)]
+pub struct ResponseUrl {
+    url: Url,
+    received_at: OffsetDateTime,
+    uses: AtomicU8,
+}
+
+impl ResponseUrl {
+    /// Starts the 30 minutes from now. Create it as soon as the request is
+    /// received.
+    pub fn new(url: Url) -> Self {
+        Self::received_at(url, OffsetDateTime::now_utc())
+    }
+
+    pub fn received_at(url: Url, received_at: OffsetDateTime) -> Self {
+        Self {
+            url,
+            received_at,
+            uses: AtomicU8::new(0),
+        }
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn expires_at(&self) -> OffsetDateTime {
+        self.received_at + VALID_FOR
+    }
+
+    pub fn remaining_uses(&self) -> u8 {
+        MAX_USES - self.uses.load(Ordering::SeqCst)
+    }
+
+    /// Counts a use, even if the request then fails, since Slack may have
+    /// counted it too
+    fn take_use(&self) -> Result<(), Error> {
+        if OffsetDateTime::now_utc() >= self.expires_at() {
+            return Err(Error::Slack("expired_url".to_string()));
+        }
+
+        self.uses
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |uses| {
+                (uses < MAX_USES).then_some(uses + 1)
+            })
+            .map(|_| ())
+            .map_err(|_| Error::Slack("used_url".to_string()))
+    }
+}
+
+/// Posts a message to a `response_url`
+pub async fn send(
+    basic_client: &BasicClient,
+    response_url: &ResponseUrl,
+    message: &ResponseMessage,
+) -> Result<(), Error> {
+    post(basic_client, response_url, serde_json::to_value(message)?).await
+}
+
+/// Deletes the message the interaction came from
+pub async fn delete(basic_client: &BasicClient, response_url: &ResponseUrl) -> Result<(), Error> {
+    post(
+        basic_client,
+        response_url,
+        serde_json::json!({ "delete_original": true }),
+    )
+    .await
+}
+
+async fn post(
+    basic_client: &BasicClient,
+    response_url: &ResponseUrl,
+    body: serde_json::Value,
+) -> Result<(), Error> {
+    response_url.take_use()?;
+
+    let res = basic_client
+        .client()
+        .post(response_url.url().clone())
+        .json(&body)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST response_url -> {}", res.status());
+
+    // Depending on the message, Slack answers with JSON or just `ok` or the
+    // error as plain text.
+    let body = res.text().await.map_err(Error::Deserialize)?;
+    let body = body.trim();
+
+    if body.is_empty() || body == "ok" {
+        return Ok(());
+    }
+
+    match serde_json::from_str::<PostResponse>(body) {
+        Ok(PostResponse::Ok { ok: true }) => Ok(()),
+        Ok(PostResponse::Error { error }) => Err(Error::Slack(error)),
+        Ok(PostResponse::Ok { ok: false }) | Err(_) => Err(Error::Slack(body.to_string())),
+    }
+}
@@ -0,0 +1,104 @@
+use slack_http_types::{
+    error::Error,
+    page::NumberedPage,
+    search::{AllResponse, FilesResponse, MessagesResponse},
+};
+use url::Url;
+
+use crate::client::AuthClient;
+pub use slack_http_types::files::File;
+pub use slack_http_types::search::{
+    AllResults, MatchChannel, MessageMatch, SearchOptions, Sort, SortDirection, HIGHLIGHT_END,
+    HIGHLIGHT_START,
+};
+
+const MESSAGES: &str = "https://slack.com/api/search.messages";
+const FILES: &str = "https://slack.com/api/search.files";
+const ALL: &str = "https://slack.com/api/search.all";
+
+/// Searches messages. Needs a user token. Pages start at 1.
+pub async fn messages(
+    auth_client: &AuthClient,
+    page: u32,
+    options: &SearchOptions,
+) -> Result<NumberedPage<MessageMatch>, Error> {
+    let url = Url::parse_with_params(MESSAGES, &options.query_params(page))?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", MESSAGES, res.status());
+
+    let json = res
+        .json::<MessagesResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        MessagesResponse::Ok { messages } => Ok(messages.into()),
+        MessagesResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Searches files. Needs a user token. Pages start at 1.
+pub async fn files(
+    auth_client: &AuthClient,
+    page: u32,
+    options: &SearchOptions,
+) -> Result<NumberedPage<File>, Error> {
+    let url = Url::parse_with_params(FILES, &options.query_params(page))?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", FILES, res.status());
+
+    let json = res
+        .json::<FilesResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        FilesResponse::Ok { files } => Ok(files.into()),
+        FilesResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Searches both messages and files. Needs a user token. Pages start at 1.
+pub async fn all(
+    auth_client: &AuthClient,
+    page: u32,
+    options: &SearchOptions,
+) -> Result<AllResults, Error> {
+    let url = Url::parse_with_params(ALL, &options.query_params(page))?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", ALL, res.status());
+
+    let json = res
+        .json::<AllResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        AllResponse::Ok { messages, files } => Ok(AllResults {
+            messages: messages.into(),
+            files: files.into(),
+        }),
+        AllResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
@@ -1,9 +1,14 @@
 use crate::client::AuthClient;
 use reqwest::Url;
-pub use slack_http_types::team::{Id, Team};
-use slack_http_types::{error::Error, team::InfoResponse};
+pub use slack_http_types::team::{ConnectedWorkspace, ExternalTeam, Id, Team};
+use slack_http_types::{
+    error::Error,
+    page::{Cursor, Limit, Page},
+    team::{ExternalTeamsListResponse, InfoResponse},
+};
 
 const GET_TEAM_INFO: &str = "https://slack.com/api/team.info";
+const LIST_EXTERNAL_TEAMS: &str = "https://slack.com/api/team.externalTeams.list";
 
 pub async fn info(auth_client: &AuthClient, team_id: &Id) -> Result<Team, Error> {
     let url = Url::parse_with_params(GET_TEAM_INFO, &[("team", team_id.0.as_str())])?;
@@ -30,3 +35,39 @@ pub async fn info(auth_client: &AuthClient, team_id: &Id) -> Result<Team, Error>
         }
     }
 }
+
+/// Lists the external organizations the team is connected to via Slack Connect
+pub async fn list_external_teams(
+    auth_client: &AuthClient,
+    cursor: &Cursor,
+    limit: &Limit,
+) -> Result<Page<ExternalTeam>, Error> {
+    let limit = limit.get().to_string();
+
+    let url = Url::parse_with_params(
+        LIST_EXTERNAL_TEAMS,
+        &[("cursor", cursor.as_str()), ("limit", limit.as_str())],
+    )?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", LIST_EXTERNAL_TEAMS, res.status());
+
+    let json = res
+        .json::<ExternalTeamsListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ExternalTeamsListResponse::Ok {
+            organizations,
+            response_metadata,
+        } => Ok(Page::new(organizations, Cursor::from(response_metadata))),
+        ExternalTeamsListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
@@ -1,13 +1,29 @@
 use crate::client::AuthClient;
-pub use slack_http_types::user::{Id, User};
+use reqwest::multipart::{Form, Part};
+pub use slack_http_types::user::{
+    Id, PhotoCrop, Presence, PresenceStatus, Profile, ProfileField, ProfileUpdate, SetPresence,
+    User,
+};
 use slack_http_types::{
     error::Error,
     page::{Cursor, Limit, Page},
-    user::ListResponse,
+    user::{
+        DeletePhotoResponse, GetPresenceResponse, InfoResponse, ListResponse,
+        LookupByEmailResponse, ProfileGetResponse, ProfileSetResponse, SetPhotoResponse,
+        SetPresenceResponse,
+    },
 };
 use url::Url;
 
 const LIST: &str = "https://slack.com/api/users.list";
+const INFO: &str = "https://slack.com/api/users.info";
+const LOOKUP_BY_EMAIL: &str = "https://slack.com/api/users.lookupByEmail";
+const PROFILE_GET: &str = "https://slack.com/api/users.profile.get";
+const PROFILE_SET: &str = "https://slack.com/api/users.profile.set";
+const GET_PRESENCE: &str = "https://slack.com/api/users.getPresence";
+const SET_PRESENCE: &str = "https://slack.com/api/users.setPresence";
+const SET_PHOTO: &str = "https://slack.com/api/users.setPhoto";
+const DELETE_PHOTO: &str = "https://slack.com/api/users.deletePhoto";
 
 pub async fn list(
     auth_client: &AuthClient,
@@ -48,3 +64,240 @@ pub async fn list(
         ListResponse::Error { error, .. } => Err(Error::Slack(error)),
     }
 }
+
+pub async fn info(auth_client: &AuthClient, user_id: &Id) -> Result<User, Error> {
+    let url = Url::parse_with_params(
+        INFO,
+        &[("user", user_id.as_str()), ("include_locale", "true")],
+    )?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", INFO, res.status());
+
+    let json = res
+        .json::<InfoResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        InfoResponse::Ok { user } => Ok(*user),
+        InfoResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Finds a user by their email. Requires the `users:read.email` scope.
+pub async fn lookup_by_email(auth_client: &AuthClient, email: &str) -> Result<User, Error> {
+    let url = Url::parse_with_params(LOOKUP_BY_EMAIL, &[("email", email)])?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", LOOKUP_BY_EMAIL, res.status());
+
+    let json = res
+        .json::<LookupByEmailResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        LookupByEmailResponse::Ok { user } => Ok(*user),
+        LookupByEmailResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Gets a user's profile. Defaults to the authed user if `user_id` is `None`.
+pub async fn profile_get(auth_client: &AuthClient, user_id: Option<&Id>) -> Result<Profile, Error> {
+    let mut query_params = Vec::new();
+
+    if let Some(user_id) = user_id {
+        query_params.push(("user", user_id.as_str()));
+    }
+
+    let url = Url::parse_with_params(PROFILE_GET, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", PROFILE_GET, res.status());
+
+    let json = res
+        .json::<ProfileGetResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ProfileGetResponse::Ok { profile } => Ok(*profile),
+        ProfileGetResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Updates a user's profile. Defaults to the authed user if `user_id` is
+/// `None`; setting someone else's profile needs an admin's user token.
+pub async fn profile_set(
+    auth_client: &AuthClient,
+    user_id: Option<&Id>,
+    profile: &ProfileUpdate,
+) -> Result<Profile, Error> {
+    let url = Url::parse(PROFILE_SET)?;
+
+    let mut body = serde_json::json!({ "profile": profile });
+
+    if let Some(user_id) = user_id {
+        body["user"] = serde_json::json!(user_id);
+    }
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", PROFILE_SET, res.status());
+
+    let json = res
+        .json::<ProfileSetResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ProfileSetResponse::Ok { profile } => Ok(*profile),
+        ProfileSetResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Gets a user's presence. Defaults to the authed user if `user_id` is `None`.
+pub async fn get_presence(
+    auth_client: &AuthClient,
+    user_id: Option<&Id>,
+) -> Result<Presence, Error> {
+    let mut query_params = Vec::new();
+
+    if let Some(user_id) = user_id {
+        query_params.push(("user", user_id.as_str()));
+    }
+
+    let url = Url::parse_with_params(GET_PRESENCE, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", GET_PRESENCE, res.status());
+
+    let json = res
+        .json::<GetPresenceResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        GetPresenceResponse::Ok(presence) => Ok(presence),
+        GetPresenceResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Manually sets the authed user's presence
+pub async fn set_presence(auth_client: &AuthClient, presence: SetPresence) -> Result<(), Error> {
+    let url = Url::parse_with_params(SET_PRESENCE, &[("presence", presence.as_str())])?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", SET_PRESENCE, res.status());
+
+    let json = res
+        .json::<SetPresenceResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        SetPresenceResponse::Ok { .. } => Ok(()),
+        SetPresenceResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Sets the authed user's profile photo. Needs a user token. `file_name`
+/// should have the image's extension so Slack can tell what kind it is.
+pub async fn set_photo(
+    auth_client: &AuthClient,
+    image: Vec<u8>,
+    file_name: &str,
+    crop: Option<PhotoCrop>,
+) -> Result<(), Error> {
+    let url = Url::parse(SET_PHOTO)?;
+
+    let mut form = Form::new().part("image", Part::bytes(image).file_name(file_name.to_string()));
+
+    if let Some(crop) = crop {
+        form = form
+            .text("crop_x", crop.x.to_string())
+            .text("crop_y", crop.y.to_string())
+            .text("crop_w", crop.width.to_string());
+    }
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", SET_PHOTO, res.status());
+
+    let json = res
+        .json::<SetPhotoResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        SetPhotoResponse::Ok { .. } => Ok(()),
+        SetPhotoResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Removes the authed user's profile photo. Needs a user token.
+pub async fn delete_photo(auth_client: &AuthClient) -> Result<(), Error> {
+    let url = Url::parse(DELETE_PHOTO)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", DELETE_PHOTO, res.status());
+
+    let json = res
+        .json::<DeletePhotoResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        DeletePhotoResponse::Ok { .. } => Ok(()),
+        DeletePhotoResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
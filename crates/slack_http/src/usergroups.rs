@@ -0,0 +1,240 @@
+use slack_http_types::{
+    error::Error,
+    user,
+    usergroups::{
+        CreateResponse, DisableResponse, EnableResponse, ListResponse, UpdateResponse,
+        UsersListResponse, UsersUpdateResponse,
+    },
+};
+use url::Url;
+
+use crate::client::AuthClient;
+pub use slack_http_types::usergroups::{
+    Id, ListOptions, NewUsergroup, Prefs, Usergroup, UsergroupUpdate,
+};
+
+const CREATE: &str = "https://slack.com/api/usergroups.create";
+const UPDATE: &str = "https://slack.com/api/usergroups.update";
+const DISABLE: &str = "https://slack.com/api/usergroups.disable";
+const ENABLE: &str = "https://slack.com/api/usergroups.enable";
+const LIST: &str = "https://slack.com/api/usergroups.list";
+const USERS_LIST: &str = "https://slack.com/api/usergroups.users.list";
+const USERS_UPDATE: &str = "https://slack.com/api/usergroups.users.update";
+
+pub async fn create(
+    auth_client: &AuthClient,
+    usergroup: &NewUsergroup,
+) -> Result<Usergroup, Error> {
+    let url = Url::parse_with_params(CREATE, &usergroup.query_params())?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", CREATE, res.status());
+
+    let json = res
+        .json::<CreateResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        CreateResponse::Ok { usergroup } => Ok(*usergroup),
+        CreateResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn update(
+    auth_client: &AuthClient,
+    usergroup_id: &Id,
+    update: &UsergroupUpdate,
+) -> Result<Usergroup, Error> {
+    let mut query_params = update.query_params();
+    query_params.push(("usergroup", usergroup_id.0.clone()));
+
+    let url = Url::parse_with_params(UPDATE, &query_params)?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", UPDATE, res.status());
+
+    let json = res
+        .json::<UpdateResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        UpdateResponse::Ok { usergroup } => Ok(*usergroup),
+        UpdateResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Disables a user group. It keeps its members and can be enabled again.
+pub async fn disable(auth_client: &AuthClient, usergroup_id: &Id) -> Result<Usergroup, Error> {
+    let url = Url::parse_with_params(
+        DISABLE,
+        &[
+            ("usergroup", usergroup_id.as_str()),
+            ("include_count", "true"),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", DISABLE, res.status());
+
+    let json = res
+        .json::<DisableResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        DisableResponse::Ok { usergroup } => Ok(*usergroup),
+        DisableResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn enable(auth_client: &AuthClient, usergroup_id: &Id) -> Result<Usergroup, Error> {
+    let url = Url::parse_with_params(
+        ENABLE,
+        &[
+            ("usergroup", usergroup_id.as_str()),
+            ("include_count", "true"),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", ENABLE, res.status());
+
+    let json = res
+        .json::<EnableResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        EnableResponse::Ok { usergroup } => Ok(*usergroup),
+        EnableResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn list(
+    auth_client: &AuthClient,
+    options: &ListOptions,
+) -> Result<Vec<Usergroup>, Error> {
+    let url = Url::parse_with_params(LIST, &options.query_params())?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", LIST, res.status());
+
+    let json = res
+        .json::<ListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        ListResponse::Ok { usergroups } => Ok(usergroups),
+        ListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+pub async fn users_list(
+    auth_client: &AuthClient,
+    usergroup_id: &Id,
+    include_disabled: bool,
+) -> Result<Vec<user::Id>, Error> {
+    let include_disabled = include_disabled.to_string();
+
+    let url = Url::parse_with_params(
+        USERS_LIST,
+        &[
+            ("usergroup", usergroup_id.as_str()),
+            ("include_disabled", include_disabled.as_str()),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("GET {} -> {}", USERS_LIST, res.status());
+
+    let json = res
+        .json::<UsersListResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        UsersListResponse::Ok { users } => Ok(users),
+        UsersListResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Replaces every member of a user group with `users`. Slack doesn't allow
+/// an empty group, so `users` needs at least one user.
+pub async fn users_update(
+    auth_client: &AuthClient,
+    usergroup_id: &Id,
+    users: &[user::Id],
+) -> Result<Usergroup, Error> {
+    let users = users
+        .iter()
+        .map(|user| user.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let url = Url::parse_with_params(
+        USERS_UPDATE,
+        &[
+            ("usergroup", usergroup_id.as_str()),
+            ("users", users.as_str()),
+            ("include_count", "true"),
+        ],
+    )?;
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", USERS_UPDATE, res.status());
+
+    let json = res
+        .json::<UsersUpdateResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        UsersUpdateResponse::Ok { usergroup } => Ok(*usergroup),
+        UsersUpdateResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
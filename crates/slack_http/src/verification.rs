@@ -0,0 +1,212 @@
+use std::{fmt, sync::Arc};
+
+use ring::hmac;
+use thiserror::Error;
+use time::{Duration, OffsetDateTime};
+
+/// Header with the UNIX timestamp Slack signed the request at
+pub const TIMESTAMP_HEADER: &str = "x-slack-request-timestamp";
+/// Header with Slack's signature, prefixed with its version, e.g. `v0=`
+pub const SIGNATURE_HEADER: &str = "x-slack-signature";
+
+const SIGNATURE_VERSION: &str = "v0";
+
+#[derive(Debug, Error)]
+pub enum VerificationError {
+    #[error("computed digest and slack's signature do not match")]
+    DigestMismatch,
+    #[error("provided slack signature isn't a valid hex")]
+    SlackSignatureNotHex,
+    #[error("timestamp is older than the allowed tolerance")]
+    TimestampTooOld,
+    #[error("timestamp is further in the future than the allowed tolerance")]
+    TimestampInFuture,
+    #[error("timestamp is not in a valid UNIX timestamp format")]
+    InvalidTimestamp,
+    #[error("request has no `{0}` header")]
+    MissingHeader(&'static str),
+    #[error("`{0}` header isn't visible ASCII")]
+    InvalidHeader(&'static str),
+    #[error("signature version isn't `v0`")]
+    UnsupportedVersion,
+}
+
+/// Where a `Verifier` gets the current time from. Implemented for closures
+/// returning an `OffsetDateTime`, so tests can pin it.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> OffsetDateTime;
+}
+
+/// The system's clock
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+/// Verifies that requests are from Slack and recent enough not to be replays
+#[derive(Clone)]
+pub struct Verifier {
+    key: hmac::Key,
+    tolerance: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> OffsetDateTime + Send + Sync,
+{
+    fn now(&self) -> OffsetDateTime {
+        self()
+    }
+}
+
+impl Verifier {
+    /// Accepts timestamps up to 5 minutes away from the system's clock, as
+    /// Slack recommends
+    pub fn new(signing_secret: &[u8]) -> Self {
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, signing_secret),
+            tolerance: Duration::minutes(5),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// How far a request's timestamp can be from the clock, in either
+    /// direction. A negative tolerance is taken as its absolute value.
+    pub fn set_tolerance(self, tolerance: Duration) -> Self {
+        Self {
+            tolerance: tolerance.abs(),
+            ..self
+        }
+    }
+
+    pub fn set_clock(self, clock: impl Clock + 'static) -> Self {
+        Self {
+            clock: Arc::new(clock),
+            ..self
+        }
+    }
+
+    /// Verifies a request, reading the timestamp and signature from its
+    /// headers. The body is taken as it was received, so it doesn't need to
+    /// be UTF-8.
+    pub fn verify_request(
+        &self,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<(), VerificationError> {
+        let timestamp = header_str(headers, TIMESTAMP_HEADER)?;
+        let signature = header_str(headers, SIGNATURE_HEADER)?;
+
+        let signature = signature
+            .strip_prefix(SIGNATURE_VERSION)
+            .and_then(|sig| sig.strip_prefix('='))
+            .ok_or(VerificationError::UnsupportedVersion)?;
+
+        self.verify(timestamp, signature, body)
+    }
+
+    /// Verifies a body against the raw timestamp and the hex signature,
+    /// without its `v0=` prefix
+    pub fn verify(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), VerificationError> {
+        let timestamp_num: i64 = timestamp
+            .parse()
+            .map_err(|_| VerificationError::InvalidTimestamp)?;
+
+        let slack_datetime = OffsetDateTime::from_unix_timestamp(timestamp_num)
+            .map_err(|_| VerificationError::InvalidTimestamp)?;
+
+        // Any two `OffsetDateTime`s are less than a `Duration::MAX` apart, so
+        // unlike adding the tolerance to `now` this can't overflow
+        let skew = self.clock.now() - slack_datetime;
+
+        if skew.abs() > self.tolerance {
+            return Err(if skew.is_positive() {
+                VerificationError::TimestampTooOld
+            } else {
+                VerificationError::TimestampInFuture
+            });
+        }
+
+        let signature = decode_hex(signature).ok_or(VerificationError::SlackSignatureNotHex)?;
+
+        let mut basestring = format!("{}:{}:", SIGNATURE_VERSION, timestamp).into_bytes();
+        basestring.extend_from_slice(body);
+
+        // `hmac::verify` compares in constant time so the signature can't be
+        // guessed byte by byte from response times
+        hmac::verify(&self.key, &basestring, &signature)
+            .map_err(|_| VerificationError::DigestMismatch)
+    }
+}
+
+impl fmt::Debug for Verifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Verifier")
+            .field("tolerance", &self.tolerance)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Verifies if the request's body is from Slack. `signature` is the hex
+/// digest, with or without the `v0=` prefix of the header.
+pub fn verify(
+    signing_secret: &[u8],
+    timestamp: &str,
+    signature: &str,
+    message: &str,
+) -> Result<(), VerificationError> {
+    let signature = signature
+        .strip_prefix(SIGNATURE_VERSION)
+        .and_then(|sig| sig.strip_prefix('='))
+        .unwrap_or(signature);
+
+    Verifier::new(signing_secret).verify(timestamp, signature, message.as_bytes())
+}
+
+/// Verifies if a request is from Slack, reading the timestamp and signature
+/// from its headers. The body is taken as it was received, so it doesn't
+/// need to be UTF-8.
+pub fn verify_request(
+    signing_secret: &[u8],
+    headers: &http::HeaderMap,
+    body: &[u8],
+) -> Result<(), VerificationError> {
+    Verifier::new(signing_secret).verify_request(headers, body)
+}
+
+fn header_str<'a>(
+    headers: &'a http::HeaderMap,
+    name: &'static str,
+) -> Result<&'a str, VerificationError> {
+    headers
+        .get(name)
+        .ok_or(VerificationError::MissingHeader(name))?
+        .to_str()
+        .map_err(|_| VerificationError::InvalidHeader(name))
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
@@ -0,0 +1,153 @@
+use slack_http_types::{
+    error::Error,
+    user,
+    views::{OpenResponse, PublishResponse, PushResponse, UpdateResponse},
+};
+use url::Url;
+
+use crate::client::AuthClient;
+pub use slack_http_types::views::{Home, Id, Kind, Modal, TriggerId, View};
+
+const OPEN: &str = "https://slack.com/api/views.open";
+const PUSH: &str = "https://slack.com/api/views.push";
+const UPDATE: &str = "https://slack.com/api/views.update";
+const PUBLISH: &str = "https://slack.com/api/views.publish";
+
+/// Opens a modal in response to an interaction or slash command.
+pub async fn open(
+    auth_client: &AuthClient,
+    trigger_id: &TriggerId,
+    modal: &Modal,
+) -> Result<View, Error> {
+    let url = Url::parse(OPEN)?;
+
+    let body = serde_json::json!({ "trigger_id": trigger_id, "view": modal });
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", OPEN, res.status());
+
+    let json = res
+        .json::<OpenResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        OpenResponse::Ok { view } => Ok(*view),
+        OpenResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Pushes a modal on top of the one currently open. Slack allows at most 3
+/// views in the stack.
+pub async fn push(
+    auth_client: &AuthClient,
+    trigger_id: &TriggerId,
+    modal: &Modal,
+) -> Result<View, Error> {
+    let url = Url::parse(PUSH)?;
+
+    let body = serde_json::json!({ "trigger_id": trigger_id, "view": modal });
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", PUSH, res.status());
+
+    let json = res
+        .json::<PushResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        PushResponse::Ok { view } => Ok(*view),
+        PushResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Replaces an open view. When `hash` is the one from the last time the view
+/// was read, Slack refuses with `hash_conflict` if it has changed since.
+pub async fn update(
+    auth_client: &AuthClient,
+    view_id: &Id,
+    modal: &Modal,
+    hash: Option<&str>,
+) -> Result<View, Error> {
+    let url = Url::parse(UPDATE)?;
+
+    let mut body = serde_json::json!({ "view_id": view_id, "view": modal });
+
+    if let Some(hash) = hash {
+        body["hash"] = serde_json::json!(hash);
+    }
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", UPDATE, res.status());
+
+    let json = res
+        .json::<UpdateResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        UpdateResponse::Ok { view } => Ok(*view),
+        UpdateResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
+
+/// Publishes a user's App Home tab. `hash` is the one from the last published
+/// view or the `app_home_opened` event. If the tab changed since then Slack
+/// refuses it (see `Error::is_hash_conflict`) and the view should be rebuilt
+/// from fresh data before publishing again.
+pub async fn publish(
+    auth_client: &AuthClient,
+    user_id: &user::Id,
+    home: &Home,
+    hash: Option<&str>,
+) -> Result<View, Error> {
+    let url = Url::parse(PUBLISH)?;
+
+    let mut body = serde_json::json!({ "user_id": user_id, "view": home });
+
+    if let Some(hash) = hash {
+        body["hash"] = serde_json::json!(hash);
+    }
+
+    let res = auth_client
+        .client()
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    tracing::info!("POST {} -> {}", PUBLISH, res.status());
+
+    let json = res
+        .json::<PublishResponse>()
+        .await
+        .map_err(Error::Deserialize)?;
+
+    match json {
+        PublishResponse::Ok { view } => Ok(*view),
+        PublishResponse::Error { error } => Err(Error::Slack(error)),
+    }
+}
@@ -0,0 +1,55 @@
+use slack_http_types::error::Error;
+use url::Url;
+
+use crate::client::{BasicClient, CreateClientError};
+pub use slack_http_types::webhooks::WebhookMessage;
+
+/// Posts to an incoming webhook. Needs nothing but the webhook's URL, which
+/// should be kept as secret as a token.
+#[derive(Clone, Debug)]
+pub struct WebhookClient {
+    basic_client: BasicClient,
+    url: Url,
+}
+
+impl WebhookClient {
+    pub fn new(url: Url) -> Result<Self, CreateClientError> {
+        Ok(Self::with_client(BasicClient::new()?, url))
+    }
+
+    pub fn with_client(basic_client: BasicClient, url: Url) -> Self {
+        Self { basic_client, url }
+    }
+
+    pub async fn post_text(&self, text: &str) -> Result<(), Error> {
+        self.post(&WebhookMessage::new(text.to_string())).await
+    }
+
+    /// Webhooks answer with plain text instead of JSON, so errors like
+    /// `invalid_payload`, `channel_is_archived` or `no_service` end up as
+    /// `Error::Slack` with the body as the reason
+    pub async fn post(&self, message: &WebhookMessage) -> Result<(), Error> {
+        let res = self
+            .basic_client
+            .client()
+            .post(self.url.clone())
+            .json(message)
+            .send()
+            .await
+            .map_err(Error::Request)?;
+
+        let status = res.status();
+
+        // The URL is the webhook's credential, so it stays out of the logs
+        tracing::info!("POST incoming webhook -> {}", status);
+
+        let body = res.text().await.map_err(Error::Deserialize)?;
+
+        match body.trim() {
+            "ok" => Ok(()),
+            "" if status.is_success() => Ok(()),
+            "" => Err(Error::Slack(status.to_string())),
+            error => Err(Error::Slack(error.to_string())),
+        }
+    }
+}
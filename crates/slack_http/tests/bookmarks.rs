@@ -0,0 +1,110 @@
+use slack_http::bookmarks::{self, BookmarkEdit, NewBookmark};
+use slack_http::oauth::AccessToken;
+use slack_http::{client::AuthClient, conversation, team, Cursor};
+use url::Url;
+
+pub struct TestEnv {
+    pub authed_bot_client: AuthClient,
+    pub authed_user_client: AuthClient,
+    pub invalid_bot_client: AuthClient,
+    pub invalid_user_client: AuthClient,
+    pub team_id: team::Id,
+}
+
+fn setup() -> TestEnv {
+    let bat = AccessToken(std::env::var("SLACK_BOT_ACCESS_TOKEN").unwrap());
+    let uat = AccessToken(std::env::var("SLACK_USER_ACCESS_TOKEN").unwrap());
+    let team_id = team::Id(std::env::var("SLACK_TEAM_ID").unwrap());
+
+    let authed_bot_client = slack_http::client::AuthClient::new(bat).unwrap();
+    let authed_user_client = slack_http::client::AuthClient::new(uat).unwrap();
+
+    TestEnv {
+        invalid_bot_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        invalid_user_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        authed_bot_client,
+        authed_user_client,
+        team_id,
+    }
+}
+
+async fn find_test_channel(test_env: &TestEnv) -> conversation::Id {
+    let channels = slack_http::conversation::list(
+        &test_env.authed_user_client,
+        &test_env.team_id,
+        &Cursor(None),
+        Default::default(),
+    )
+    .await
+    .unwrap();
+
+    channels
+        .results
+        .into_iter()
+        .find(|c| c.name == "test_bookmarks")
+        .unwrap()
+        .id
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// bookmarks.add, bookmarks.edit, bookmarks.list, bookmarks.remove
+
+#[tokio::test]
+async fn it_should_add_edit_list_and_remove_bookmark() {
+    let test_env = setup();
+    let channel_id = find_test_channel(&test_env).await;
+
+    let new_bookmark = NewBookmark::link(
+        "Runbook".to_string(),
+        Url::parse("https://example.com/runbook").unwrap(),
+    )
+    .set_emoji("books".to_string());
+
+    let bookmark = bookmarks::add(&test_env.authed_bot_client, &channel_id, &new_bookmark)
+        .await
+        .unwrap();
+
+    assert_eq!(bookmark.title, "Runbook");
+    assert_eq!(bookmark.kind, bookmarks::Kind::Link);
+
+    let changes = BookmarkEdit::new().set_title("Dashboard".to_string());
+
+    let bookmark = bookmarks::edit(
+        &test_env.authed_bot_client,
+        &channel_id,
+        &bookmark.id,
+        &changes,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(bookmark.title, "Dashboard");
+
+    let all = bookmarks::list(&test_env.authed_bot_client, &channel_id)
+        .await
+        .unwrap();
+
+    assert!(all.iter().any(|b| b.id == bookmark.id));
+
+    bookmarks::remove(&test_env.authed_bot_client, &channel_id, &bookmark.id)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_parse_list_bookmarks_error() {
+    let test_env = setup();
+    let channel_id = find_test_channel(&test_env).await;
+
+    let err = bookmarks::list(&test_env.invalid_bot_client, &channel_id)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
@@ -173,7 +173,7 @@ async fn bot_should_post_ephemeral_message() {
     let user = page
         .results
         .iter()
-        .find(|u| u.profile.display_name == "OWNER")
+        .find(|u| u.profile.display_name.as_deref() == Some("OWNER"))
         .unwrap();
 
     let _message = slack_http::chat::post_ephemeral(
@@ -219,7 +219,7 @@ async fn user_should_post_ephemeral_message() {
     let user = page
         .results
         .into_iter()
-        .find(|u| u.profile.display_name == "OWNER")
+        .find(|u| u.profile.display_name.as_deref() == Some("OWNER"))
         .unwrap();
 
     let _message = slack_http::chat::post_ephemeral(
@@ -265,7 +265,7 @@ async fn it_should_parse_post_ephemeral_message_error() {
     let user = page
         .results
         .iter()
-        .find(|u| u.profile.display_name == "OWNER")
+        .find(|u| u.profile.display_name.as_deref() == Some("OWNER"))
         .unwrap();
 
     let err = slack_http::chat::post_ephemeral(
@@ -143,7 +143,7 @@ async fn it_should_invite_user() {
     let member_1 = page
         .results
         .iter()
-        .find(|u| u.profile.display_name == "MEMBER_1")
+        .find(|u| u.profile.display_name.as_deref() == Some("MEMBER_1"))
         .unwrap();
 
     let channels = slack_http::conversation::list(
@@ -194,7 +194,7 @@ async fn it_should_parse_error() {
     let user = page
         .results
         .iter()
-        .find(|u| u.profile.display_name == "MEMBER_1")
+        .find(|u| u.profile.display_name.as_deref() == Some("MEMBER_1"))
         .unwrap();
 
     let channels = slack_http::conversation::list(
@@ -247,7 +247,7 @@ async fn it_should_kick_user() {
     let user = page
         .results
         .into_iter()
-        .find(|u| u.profile.display_name == "MEMBER_1")
+        .find(|u| u.profile.display_name.as_deref() == Some("MEMBER_1"))
         .unwrap();
 
     let channels = slack_http::conversation::list(
@@ -294,7 +294,7 @@ async fn it_should_parse_kick_error() {
     let user = page
         .results
         .iter()
-        .find(|u| u.profile.display_name == "MEMBER_1")
+        .find(|u| u.profile.display_name.as_deref() == Some("MEMBER_1"))
         .unwrap();
 
     let channels = slack_http::conversation::list(
@@ -402,7 +402,7 @@ async fn it_should_list_members() {
     let users: Vec<_> = page
         .results
         .iter()
-        .filter(|u| u.id.as_str() != "USLACKBOT" && u.profile.real_name.as_str() != "Polly")
+        .filter(|u| u.id.as_str() != "USLACKBOT" && u.profile.real_name.as_deref() != Some("Polly"))
         .collect();
 
     let opts = slack_http::conversation::ListOptions::new()
@@ -504,3 +504,39 @@ async fn it_should_parse_members_error() {
 
     assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// conversations.listConnectInvites
+
+#[tokio::test]
+async fn it_should_parse_list_connect_invites_error() {
+    let test_env = setup();
+
+    let err = slack_http::conversation::list_connect_invites(
+        &test_env.invalid_bot_client,
+        &Cursor(None),
+        &Limit::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// conversations.approveSharedInvite
+
+#[tokio::test]
+async fn it_should_parse_approve_shared_invite_error() {
+    let test_env = setup();
+
+    let err = slack_http::conversation::approve_shared_invite(
+        &test_env.invalid_bot_client,
+        &slack_http::conversation::InviteId("I0000000000".to_string()),
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
@@ -15,3 +15,1226 @@ pub fn it_should_deserialize_unix_ts() {
     let ts = r#"{"test": 1716700028}"#;
     let _ = serde_json::from_str::<Test>(ts).unwrap();
 }
+
+#[test]
+pub fn it_should_deserialize_bot_user_with_missing_fields() {
+    let user = r#"{
+        "id": "U0BOTBOTBOT",
+        "team_id": "T0000000000",
+        "name": "polly",
+        "deleted": false,
+        "is_bot": true,
+        "is_app_user": false,
+        "updated": 1716700028,
+        "profile": {
+            "real_name": "Polly",
+            "display_name": "",
+            "bot_id": "B0000000000",
+            "always_active": true,
+            "fields": []
+        }
+    }"#;
+
+    let user = serde_json::from_str::<slack_http_types::user::User>(user).unwrap();
+
+    assert!(user.is_bot);
+    assert!(user.profile.image_24.is_none());
+    assert_eq!(user.profile.display_name.as_deref(), Some(""));
+    assert!(user.profile.status_text.is_none());
+    assert!(user.profile.fields.is_empty());
+    assert!(user.profile.extra.contains_key("bot_id"));
+}
+
+#[test]
+pub fn it_should_deserialize_deleted_enterprise_user() {
+    let user = r#"{
+        "id": "W0000000000",
+        "deleted": true,
+        "is_email_confirmed": true,
+        "who_can_share_contact_card": "EVERYONE",
+        "enterprise_user": {
+            "id": "W0000000000",
+            "enterprise_id": "E0000000000",
+            "enterprise_name": "Acme",
+            "is_admin": false,
+            "is_owner": false,
+            "teams": ["T0000000000"]
+        },
+        "profile": {
+            "real_name": "Gone",
+            "status_expiration": 0,
+            "fields": {
+                "Xf0000000000": { "value": "Barista", "alt": "" }
+            },
+            "has_2fa": false
+        },
+        "is_invited_user": true
+    }"#;
+
+    let user = serde_json::from_str::<slack_http_types::user::User>(user).unwrap();
+
+    assert!(user.deleted);
+    assert!(user.profile.status_expiration.is_none());
+    assert_eq!(user.profile.fields["Xf0000000000"].value, "Barista");
+    assert_eq!(user.enterprise_user.unwrap().teams.len(), 1);
+    assert!(user.extra.contains_key("is_invited_user"));
+}
+
+#[test]
+pub fn it_should_check_if_dnd_is_active() {
+    let status = r#"{
+        "dnd_enabled": true,
+        "next_dnd_start_ts": 1716700000,
+        "next_dnd_end_ts": 1716703600,
+        "snooze_enabled": true,
+        "snooze_endtime": 1716800000,
+        "snooze_remaining": 1196
+    }"#;
+
+    let status = serde_json::from_str::<slack_http_types::dnd::DndStatus>(status).unwrap();
+
+    let during_schedule = OffsetDateTime::from_unix_timestamp(1716700100).unwrap();
+    let during_snooze = OffsetDateTime::from_unix_timestamp(1716790000).unwrap();
+    let after_everything = OffsetDateTime::from_unix_timestamp(1716900000).unwrap();
+
+    assert!(status.is_active_at(during_schedule));
+    assert!(status.is_active_at(during_snooze));
+    assert!(!status.is_active_at(after_everything));
+}
+
+#[test]
+pub fn it_should_round_trip_message_ts() {
+    #[derive(Debug, Deserialize)]
+    pub struct Message {
+        #[serde(deserialize_with = "slack_http_types::offset_date_time_from_unix_ts_with_nano")]
+        pub ts: OffsetDateTime,
+    }
+
+    let message = r#"{"ts": "1716700028.000100"}"#;
+    let message = serde_json::from_str::<Message>(message).unwrap();
+
+    assert_eq!(
+        slack_http_types::offset_date_time_to_unix_ts_with_nano(&message.ts),
+        "1716700028.000100"
+    );
+}
+
+#[test]
+pub fn it_should_keep_unknown_pins() {
+    use slack_http_types::pins::{ListResponse, PinnedItem};
+
+    let list = r#"{
+        "ok": true,
+        "items": [
+            {
+                "type": "message",
+                "channel": "C123ABC456",
+                "created": 1716700028,
+                "created_by": "U123ABC456",
+                "message": { "ts": "1716700028.000100", "text": "Runbook", "user": "U123ABC456" }
+            },
+            { "type": "file_comment", "created": 1716700028, "created_by": "U123ABC456" }
+        ]
+    }"#;
+
+    let ListResponse::Ok { items } = serde_json::from_str::<ListResponse>(list).unwrap() else {
+        panic!("expected pins");
+    };
+
+    assert!(matches!(items[0], PinnedItem::Message { .. }));
+    assert!(matches!(&items[1], PinnedItem::Unknown(value) if value["type"] == "file_comment"));
+}
+
+#[test]
+pub fn it_should_not_serialize_unknown_bookmark_kind() {
+    use slack_http_types::bookmarks::{Kind, NewBookmark};
+
+    assert!(serde_json::to_string(&Kind::Unknown).is_err());
+    assert_eq!(serde_json::to_string(&Kind::Link).unwrap(), r#""link""#);
+
+    let bookmark = NewBookmark::link(
+        "Runbook".to_string(),
+        url::Url::parse("https://example.com").unwrap(),
+    );
+
+    assert!(bookmark.query_params().contains(&("type", "link")));
+}
+
+#[test]
+pub fn it_should_serialize_reminder_recurrence() {
+    use slack_http_types::reminders::Recurrence;
+
+    let weekly = Recurrence::Weekly {
+        weekdays: vec![time::Weekday::Monday, time::Weekday::Friday],
+    };
+
+    assert_eq!(
+        serde_json::to_string(&weekly).unwrap(),
+        r#"{"frequency":"weekly","weekdays":["monday","friday"]}"#
+    );
+
+    assert_eq!(
+        serde_json::to_string(&Recurrence::Daily).unwrap(),
+        r#"{"frequency":"daily"}"#
+    );
+}
+
+#[test]
+pub fn it_should_deserialize_search_messages_page() {
+    use slack_http_types::{page::NumberedPage, search::MessagesResponse};
+
+    let res = r#"{
+        "ok": true,
+        "query": "deploy",
+        "messages": {
+            "total": 3,
+            "paging": { "count": 2, "total": 3, "page": 1, "pages": 2 },
+            "matches": [
+                {
+                    "iid": "cb64bdaa-c1e8-4631-8a91-0f78080113e9",
+                    "team": "T0000000000",
+                    "channel": { "id": "C0000000000", "name": "general", "is_private": false },
+                    "type": "message",
+                    "user": "U0000000000",
+                    "username": "alice",
+                    "ts": "1716700028.000200",
+                    "text": "when is the next deploy?",
+                    "permalink": "https://example.slack.com/archives/C0000000000/p1716700028000200"
+                },
+                {
+                    "team": "T0000000000",
+                    "channel": { "id": "D0000000000" },
+                    "type": "message",
+                    "ts": "1716700000.000100",
+                    "text": "deploy done",
+                    "permalink": "https://example.slack.com/archives/D0000000000/p1716700000000100"
+                }
+            ]
+        }
+    }"#;
+
+    let page: NumberedPage<_> = match serde_json::from_str::<MessagesResponse>(res).unwrap() {
+        MessagesResponse::Ok { messages } => messages.into(),
+        MessagesResponse::Error { error } => panic!("{error}"),
+    };
+
+    assert_eq!(page.results().len(), 2);
+    assert_eq!(page.next_page(), Some(2));
+    assert_eq!(page.results()[1].channel.name, None);
+}
+
+#[test]
+pub fn it_should_deserialize_usergroup_with_string_user_count() {
+    let usergroup = r#"{
+        "id": "S0614TZR7",
+        "team_id": "T060RNRCH",
+        "is_usergroup": true,
+        "name": "Team Admins",
+        "description": "A group of all Administrators on your team.",
+        "handle": "admins",
+        "is_external": false,
+        "date_create": 1446598059,
+        "date_update": 1446670362,
+        "date_delete": 0,
+        "auto_type": "admin",
+        "created_by": "USLACKBOT",
+        "updated_by": "U060RNRCZ",
+        "deleted_by": null,
+        "prefs": { "channels": [], "groups": [] },
+        "user_count": "2"
+    }"#;
+
+    let usergroup =
+        serde_json::from_str::<slack_http_types::usergroups::Usergroup>(usergroup).unwrap();
+
+    assert_eq!(usergroup.user_count, Some(2));
+    assert!(usergroup.date_delete.is_none());
+    assert!(usergroup.users.is_none());
+}
+
+#[test]
+pub fn it_should_serialize_modal_with_blocks() {
+    use slack_http_types::{
+        blocks::{Button, ButtonStyle, Divider, Input, Section, SelectOption, StaticSelect, Text},
+        views::Modal,
+    };
+
+    let modal = Modal::new(
+        "Deploy".to_string(),
+        vec![
+            Section::new(Text::mrkdwn("Pick a *service*".to_string()))
+                .set_accessory(
+                    Button::new("cancel".to_string(), "Cancel".to_string())
+                        .set_style(ButtonStyle::Danger)
+                        .into(),
+                )
+                .into(),
+            Divider::new().into(),
+            Input::new(
+                "service".to_string(),
+                "Service".to_string(),
+                StaticSelect::new(
+                    "service_select".to_string(),
+                    vec![SelectOption::new(
+                        Text::plain("Payments".to_string()),
+                        "payments".to_string(),
+                    )],
+                )
+                .into(),
+            )
+            .into(),
+        ],
+    )
+    .set_submit("Deploy".to_string());
+
+    let json = serde_json::to_value(&modal).unwrap();
+
+    assert_eq!(json["type"], "modal");
+    assert_eq!(json["title"]["type"], "plain_text");
+    assert_eq!(json["submit"]["text"], "Deploy");
+    assert!(json.get("close").is_none());
+    assert_eq!(json["blocks"][0]["type"], "section");
+    assert_eq!(json["blocks"][0]["accessory"]["type"], "button");
+    assert_eq!(json["blocks"][0]["accessory"]["style"], "danger");
+    assert_eq!(json["blocks"][1], serde_json::json!({ "type": "divider" }));
+    assert_eq!(json["blocks"][2]["block_id"], "service");
+    assert_eq!(json["blocks"][2]["element"]["type"], "static_select");
+    assert_eq!(
+        json["blocks"][2]["element"]["options"][0]["value"],
+        "payments"
+    );
+}
+
+#[test]
+pub fn it_should_deserialize_opened_view() {
+    use slack_http_types::{
+        blocks::{Block, Element},
+        views::{Kind, OpenResponse},
+    };
+
+    let res = r#"{
+        "ok": true,
+        "view": {
+            "id": "VMHU10V25",
+            "team_id": "T8N4K1JN",
+            "type": "modal",
+            "title": { "type": "plain_text", "text": "Quite a plain modal" },
+            "submit": { "type": "plain_text", "text": "Create" },
+            "blocks": [
+                {
+                    "type": "input",
+                    "block_id": "a_block_id",
+                    "label": { "type": "plain_text", "text": "A simple label", "emoji": true },
+                    "optional": false,
+                    "dispatch_action": false,
+                    "element": { "type": "plain_text_input", "action_id": "an_action_id" }
+                },
+                { "type": "rich_text", "block_id": "rt", "elements": [] }
+            ],
+            "private_metadata": "Shh it is a secret",
+            "callback_id": "identify_your_modals",
+            "external_id": "",
+            "state": { "values": {} },
+            "hash": "156772938.1827394",
+            "clear_on_close": false,
+            "notify_on_close": false,
+            "root_view_id": "VMHU10V25",
+            "previous_view_id": null,
+            "app_id": "AA4928AQ",
+            "bot_id": "BA13894H"
+        }
+    }"#;
+
+    let view = match serde_json::from_str::<OpenResponse>(res).unwrap() {
+        OpenResponse::Ok { view } => view,
+        OpenResponse::Error { error } => panic!("{error}"),
+    };
+
+    assert_eq!(view.kind, Kind::Modal);
+    assert_eq!(view.hash, "156772938.1827394");
+    assert!(matches!(
+        &view.blocks[0],
+        Block::Input(input) if matches!(input.element, Element::PlainTextInput(_))
+    ));
+    assert_eq!(view.blocks[1].block_id(), Some("rt"));
+
+    // Unknown blocks go back to Slack as they came
+    assert_eq!(
+        serde_json::to_value(&view.blocks[1]).unwrap(),
+        serde_json::json!({ "type": "rich_text", "block_id": "rt", "elements": [] })
+    );
+}
+
+#[test]
+pub fn it_should_keep_unknown_elements_and_view_types() {
+    use slack_http_types::{
+        blocks::{Block, ContextElement, Element},
+        views::{Kind, View},
+    };
+
+    let view = r#"{
+        "id": "VMHU10V25",
+        "team_id": "T8N4K1JN",
+        "type": "workflow_step",
+        "blocks": [
+            {
+                "type": "actions",
+                "elements": [{ "type": "overflow", "action_id": "more", "options": [] }]
+            },
+            {
+                "type": "context",
+                "elements": [{ "type": "user", "user_id": "U123ABC456" }]
+            }
+        ],
+        "hash": "156772938.1827394"
+    }"#;
+
+    let view = serde_json::from_str::<View>(view).unwrap();
+
+    assert_eq!(view.kind, Kind::Unknown);
+
+    match &view.blocks[0] {
+        Block::Actions(actions) => assert!(matches!(
+            &actions.elements[0],
+            Element::Unknown(value) if value["type"] == "overflow"
+        )),
+        block => panic!("unexpected block {block:?}"),
+    }
+
+    match &view.blocks[1] {
+        Block::Context(context) => assert!(matches!(
+            &context.elements[0],
+            ContextElement::Unknown(value) if value["user_id"] == "U123ABC456"
+        )),
+        block => panic!("unexpected block {block:?}"),
+    }
+
+    assert_eq!(
+        serde_json::to_value(&view.blocks[1]).unwrap(),
+        serde_json::json!({
+            "type": "context",
+            "elements": [{ "type": "user", "user_id": "U123ABC456" }]
+        })
+    );
+}
+
+#[test]
+pub fn it_should_serialize_home_view() {
+    use slack_http_types::{blocks::Header, views::Home};
+
+    let home = Home::new(vec![Header::new("Dashboard".to_string()).into()])
+        .set_callback_id("dashboard".to_string());
+
+    let json = serde_json::to_value(&home).unwrap();
+
+    assert_eq!(json["type"], "home");
+    assert_eq!(json["callback_id"], "dashboard");
+    assert_eq!(json["blocks"][0]["text"]["text"], "Dashboard");
+    assert!(json.get("title").is_none());
+}
+
+#[test]
+pub fn it_should_deserialize_url_verification() {
+    use slack_http_types::events::Envelope;
+
+    let body = r#"{
+        "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+        "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+        "type": "url_verification"
+    }"#;
+
+    match serde_json::from_str::<Envelope>(body).unwrap() {
+        Envelope::UrlVerification(verification) => assert_eq!(
+            verification.challenge,
+            "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+        ),
+        envelope => panic!("unexpected envelope {envelope:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_deserialize_app_mention_callback() {
+    use slack_http_types::events::{Envelope, Event};
+
+    let body = r#"{
+        "token": "ZZZZZZWSxiZZZ2yIvs3peJ",
+        "team_id": "T061EG9R6",
+        "api_app_id": "A0MDYCDME",
+        "event": {
+            "type": "app_mention",
+            "user": "U061F7AUR",
+            "text": "<@U0LAN0Z89> is it everything a river should be?",
+            "ts": "1515449522.000016",
+            "channel": "C0LAN2Q65",
+            "event_ts": "1515449522.000016"
+        },
+        "type": "event_callback",
+        "event_id": "Ev0LAN670R",
+        "event_time": 1515449522,
+        "authorizations": [
+            {
+                "enterprise_id": null,
+                "team_id": "T061EG9R6",
+                "user_id": "U0LAN0Z89",
+                "is_bot": true,
+                "is_enterprise_install": false
+            }
+        ],
+        "is_ext_shared_channel": false,
+        "event_context": "4-eyJldCI6ImFwcF9tZW50aW9uIn0"
+    }"#;
+
+    let callback = match serde_json::from_str::<Envelope>(body) {
+        Ok(Envelope::EventCallback(callback)) => callback,
+        res => panic!("unexpected envelope {res:?}"),
+    };
+
+    assert_eq!(callback.event_id.as_str(), "Ev0LAN670R");
+    assert_eq!(callback.event_time.unix_timestamp(), 1515449522);
+    assert!(callback.authorizations[0].is_bot);
+
+    match &callback.event {
+        Event::AppMention(mention) => {
+            assert_eq!(mention.user.as_str(), "U061F7AUR");
+            assert!(mention.thread_ts.is_none());
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_deserialize_message_subtypes() {
+    use slack_http_types::events::{Event, MessageEvent, MessageSubtype};
+
+    let changed = r#"{
+        "type": "message",
+        "subtype": "message_changed",
+        "hidden": true,
+        "channel": "C123ABC456",
+        "ts": "1358878755.000001",
+        "message": {
+            "type": "message",
+            "user": "U123ABC456",
+            "text": "Hello, world!",
+            "ts": "1355517523.000005",
+            "edited": { "user": "U123ABC456", "ts": "1358878755.000001" }
+        },
+        "event_ts": "1358878755.000001"
+    }"#;
+
+    match serde_json::from_str::<Event>(changed).unwrap() {
+        Event::Message(MessageEvent::Changed(changed)) => {
+            assert_eq!(changed.message.text, "Hello, world!");
+            assert!(changed.message.edited.is_some());
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+
+    let joined = r#"{
+        "type": "message",
+        "subtype": "channel_join",
+        "channel": "C123ABC456",
+        "channel_type": "channel",
+        "user": "U123ABC456",
+        "text": "<@U123ABC456> has joined the channel",
+        "ts": "1358877455.000010",
+        "event_ts": "1358877455.000010"
+    }"#;
+
+    match serde_json::from_str::<Event>(joined).unwrap() {
+        Event::Message(MessageEvent::Message(message)) => {
+            assert_eq!(message.subtype, Some(MessageSubtype::ChannelJoin))
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+
+    let huddle = r#"{
+        "type": "message",
+        "subtype": "huddle_thread",
+        "channel": "C123ABC456",
+        "ts": "1358877455.000010",
+        "event_ts": "1358877455.000010"
+    }"#;
+
+    match serde_json::from_str::<Event>(huddle).unwrap() {
+        Event::Message(MessageEvent::Message(message)) => assert_eq!(
+            message.subtype,
+            Some(MessageSubtype::Other("huddle_thread".to_string()))
+        ),
+        event => panic!("unexpected event {event:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_deserialize_reaction_added() {
+    use slack_http_types::events::{Event, ReactionItem};
+
+    let event = r#"{
+        "type": "reaction_added",
+        "user": "U123ABC456",
+        "reaction": "thumbsup",
+        "item_user": "U222222222",
+        "item": { "type": "message", "channel": "C123ABC456", "ts": "1360782400.498405" },
+        "event_ts": "1360782804.083113"
+    }"#;
+
+    match serde_json::from_str::<Event>(event).unwrap() {
+        Event::ReactionAdded(reaction) => {
+            assert_eq!(reaction.reaction.as_str(), "thumbsup");
+            assert!(matches!(reaction.item, ReactionItem::Message { .. }));
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_keep_unknown_event_as_json() {
+    use slack_http_types::events::Event;
+
+    let event = r#"{
+        "type": "emoji_changed",
+        "subtype": "add",
+        "name": "picard_facepalm",
+        "value": "https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif",
+        "event_ts": "1361482916.000004"
+    }"#;
+
+    let event = serde_json::from_str::<Event>(event).unwrap();
+
+    assert_eq!(event.kind(), "emoji_changed");
+
+    match event {
+        Event::Unknown(value) => assert_eq!(value["name"], "picard_facepalm"),
+        event => panic!("unexpected event {event:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_keep_malformed_event_as_json() {
+    use slack_http_types::events::{Envelope, Event};
+
+    // `item` has a shape `ReactionItem` doesn't know
+    let body = r#"{
+        "type": "event_callback",
+        "team_id": "T123ABC456",
+        "api_app_id": "A123ABC456",
+        "event": {
+            "type": "reaction_added",
+            "user": "U123ABC456",
+            "reaction": "thumbsup",
+            "item": { "type": "canvas", "canvas_id": "F123ABC456" },
+            "event_ts": "1360782804.083113"
+        },
+        "event_id": "Ev123ABC456",
+        "event_time": 1360782804,
+        "authorizations": []
+    }"#;
+
+    match serde_json::from_str::<Envelope>(body).unwrap() {
+        Envelope::EventCallback(callback) => {
+            assert_eq!(callback.event.kind(), "reaction_added");
+
+            match callback.event {
+                Event::Unknown(value) => assert_eq!(value["item"]["type"], "canvas"),
+                event => panic!("unexpected event {event:?}"),
+            }
+        }
+        envelope => panic!("unexpected envelope {envelope:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_deserialize_app_rate_limited() {
+    use slack_http_types::events::Envelope;
+
+    let body = r#"{
+        "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+        "type": "app_rate_limited",
+        "team_id": "T123456",
+        "minute_rate_limited": 1518467820,
+        "api_app_id": "A123456"
+    }"#;
+
+    match serde_json::from_str::<Envelope>(body).unwrap() {
+        Envelope::AppRateLimited(limited) => {
+            assert_eq!(limited.minute_rate_limited.unix_timestamp(), 1518467820)
+        }
+        envelope => panic!("unexpected envelope {envelope:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_deserialize_view_submission_state() {
+    use slack_http_types::interactivity::{ElementValue, Payload};
+
+    let payload = r#"{
+        "type": "view_submission",
+        "team": { "id": "T0CAG", "domain": "acme-creamery" },
+        "user": { "id": "U0CA5", "username": "Amy McGee", "name": "Amy McGee", "team_id": "T3MDE" },
+        "api_app_id": "A0CA5",
+        "token": "Shh_its_a_seekrit",
+        "trigger_id": "12466734323.1395872398",
+        "view": {
+            "id": "VNHU13V36",
+            "team_id": "T0CAG",
+            "type": "modal",
+            "blocks": [],
+            "private_metadata": "shhh-its-secret",
+            "callback_id": "modal-with-inputs",
+            "state": {
+                "values": {
+                    "notes": { "notes_input": { "type": "plain_text_input", "value": "ship it" } },
+                    "date": { "date_input": { "type": "datepicker", "selected_date": "2024-05-26" } },
+                    "time": { "time_input": { "type": "timepicker", "selected_time": "09:30" } },
+                    "owner": { "owner_input": { "type": "users_select", "selected_user": null } },
+                    "service": {
+                        "service_input": {
+                            "type": "static_select",
+                            "selected_option": {
+                                "text": { "type": "plain_text", "text": "Payments", "emoji": true },
+                                "value": "payments"
+                            }
+                        }
+                    },
+                    "channels": {
+                        "channels_input": {
+                            "type": "multi_conversations_select",
+                            "selected_conversations": ["C123ABC456", "C234BCD567"]
+                        }
+                    },
+                    "rating": { "rating_input": { "type": "number_input", "value": "5" } },
+                    "attachment": { "attachment_input": { "type": "file_input", "files": [] } }
+                }
+            },
+            "hash": "156663117.cd33ad1f",
+            "response_urls": []
+        }
+    }"#;
+
+    let submission = match serde_json::from_str::<Payload>(payload).unwrap() {
+        Payload::ViewSubmission(submission) => submission,
+        payload => panic!("unexpected payload {payload:?}"),
+    };
+
+    let values = &submission.view.state.values;
+
+    assert!(matches!(
+        &values["notes"]["notes_input"],
+        ElementValue::PlainTextInput { value: Some(value) } if value == "ship it"
+    ));
+    assert!(matches!(
+        values["date"]["date_input"],
+        ElementValue::Datepicker { selected_date: Some(date) }
+            if date == time::Date::from_calendar_date(2024, time::Month::May, 26).unwrap()
+    ));
+    assert!(matches!(
+        values["time"]["time_input"],
+        ElementValue::Timepicker { selected_time: Some(time) }
+            if time == time::Time::from_hms(9, 30, 0).unwrap()
+    ));
+    assert!(matches!(
+        values["owner"]["owner_input"],
+        ElementValue::UsersSelect {
+            selected_user: None
+        }
+    ));
+    assert!(matches!(
+        &values["service"]["service_input"],
+        ElementValue::StaticSelect { selected_option: Some(option) } if option.value == "payments"
+    ));
+    assert!(matches!(
+        &values["channels"]["channels_input"],
+        ElementValue::MultiConversationsSelect { selected_conversations } if selected_conversations.len() == 2
+    ));
+    assert!(matches!(
+        &values["rating"]["rating_input"],
+        ElementValue::NumberInput { value: Some(value) } if value == "5"
+    ));
+    assert!(matches!(
+        values["attachment"]["attachment_input"],
+        ElementValue::Unknown
+    ));
+}
+
+#[test]
+pub fn it_should_deserialize_block_actions_from_form() {
+    use slack_http_types::interactivity::{Container, ElementValue, Payload};
+
+    let payload = r#"{
+        "type": "block_actions",
+        "team": { "id": "T9TK3CUKW", "domain": "example" },
+        "user": { "id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW" },
+        "api_app_id": "AABA1ABCD",
+        "container": {
+            "type": "message",
+            "message_ts": "1548261231.000200",
+            "channel_id": "CBR2V3XEX",
+            "is_ephemeral": false
+        },
+        "trigger_id": "12321423423.333649436676.d8c1bb837935619ccad0f624c448ffb3",
+        "channel": { "id": "CBR2V3XEX", "name": "review-updates" },
+        "message": {
+            "bot_id": "BAH5CA16Z",
+            "type": "message",
+            "text": "This content can't be displayed.",
+            "user": "UAJ2RU415",
+            "ts": "1548261231.000200",
+            "blocks": [
+                { "type": "divider", "block_id": "divider" },
+                {
+                    "type": "actions",
+                    "block_id": "approval",
+                    "elements": [
+                        {
+                            "type": "button",
+                            "action_id": "approve",
+                            "text": { "type": "plain_text", "text": "Approve", "emoji": true },
+                            "value": "click_me_123"
+                        }
+                    ]
+                }
+            ]
+        },
+        "state": { "values": {} },
+        "response_url": "https://hooks.slack.com/actions/AABA1ABCD/1232321423432/D09sSasdasdAS9091209",
+        "actions": [
+            {
+                "action_id": "approve",
+                "block_id": "approval",
+                "text": { "type": "plain_text", "text": "Approve", "emoji": true },
+                "value": "click_me_123",
+                "type": "button",
+                "action_ts": "1548426417.840180"
+            }
+        ]
+    }"#;
+
+    let body: String = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("payload", payload)
+        .finish();
+
+    let actions = match Payload::from_form(body.as_bytes()).unwrap() {
+        Payload::BlockActions(actions) => actions,
+        payload => panic!("unexpected payload {payload:?}"),
+    };
+
+    assert!(matches!(actions.container, Container::Message { .. }));
+    assert_eq!(actions.message.unwrap().blocks.len(), 2);
+    assert_eq!(actions.actions[0].action_id, "approve");
+    assert!(matches!(
+        &actions.actions[0].value,
+        ElementValue::Button { value: Some(value) } if value == "click_me_123"
+    ));
+}
+
+#[test]
+pub fn it_should_reject_form_without_payload() {
+    use slack_http_types::interactivity::{Payload, PayloadError};
+
+    let err = Payload::from_form(b"token=abc&team_id=T123").unwrap_err();
+
+    assert!(matches!(err, PayloadError::MissingPayload));
+}
+
+#[test]
+pub fn it_should_deserialize_shortcut_and_suggestion() {
+    use slack_http_types::interactivity::Payload;
+
+    let shortcut = r#"{
+        "type": "shortcut",
+        "token": "XXXXXXXXXXXXX",
+        "action_ts": "1581106241.371594",
+        "team": { "id": "TXXXXXXXX", "domain": "shortcuts-test" },
+        "user": { "id": "UXXXXXXXXX", "username": "aman", "team_id": "TXXXXXXXX" },
+        "callback_id": "shortcut_create_task",
+        "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638"
+    }"#;
+
+    match serde_json::from_str::<Payload>(shortcut).unwrap() {
+        Payload::Shortcut(shortcut) => assert_eq!(shortcut.callback_id, "shortcut_create_task"),
+        payload => panic!("unexpected payload {payload:?}"),
+    }
+
+    let suggestion = r#"{
+        "type": "block_suggestion",
+        "user": { "id": "UXXXXXXXXX", "username": "aman", "team_id": "TXXXXXXXX" },
+        "team": { "id": "TXXXXXXXX", "domain": "shortcuts-test" },
+        "api_app_id": "AXXXXXXXX",
+        "container": { "type": "view", "view_id": "VXXXXXXXX" },
+        "action_id": "service_select",
+        "block_id": "service",
+        "value": "pay"
+    }"#;
+
+    match serde_json::from_str::<Payload>(suggestion).unwrap() {
+        Payload::BlockSuggestion(suggestion) => assert_eq!(suggestion.value, "pay"),
+        payload => panic!("unexpected payload {payload:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_get_submitted_values_from_state() {
+    use slack_http_types::interactivity::State;
+
+    let state = r#"{
+        "values": {
+            "title": { "title_input": { "type": "plain_text_input", "value": "Outage" } },
+            "summary": { "summary_input": { "type": "plain_text_input", "value": null } },
+            "severity": {
+                "severity_input": {
+                    "type": "radio_buttons",
+                    "selected_option": { "text": { "type": "plain_text", "text": "High" }, "value": "high" }
+                }
+            },
+            "responders": {
+                "responders_input": { "type": "multi_users_select", "selected_users": ["U123ABC456"] }
+            },
+            "channel": { "channel_input": { "type": "channels_select", "selected_channel": "C123ABC456" } },
+            "due": { "due_input": { "type": "datepicker", "selected_date": "2024-05-26" } }
+        }
+    }"#;
+
+    let state = serde_json::from_str::<State>(state).unwrap();
+
+    assert_eq!(state.text("title", "title_input"), Some("Outage"));
+    assert_eq!(state.text("summary", "summary_input"), None);
+    assert_eq!(state.text("severity", "severity_input"), None);
+    assert_eq!(
+        state
+            .selected_option("severity", "severity_input")
+            .map(|option| option.value.as_str()),
+        Some("high")
+    );
+    assert_eq!(
+        state.selected_users("responders", "responders_input").len(),
+        1
+    );
+    assert!(state.selected_users("missing", "missing").is_empty());
+    assert_eq!(
+        state
+            .selected_conversation("channel", "channel_input")
+            .map(|id| id.as_str()),
+        Some("C123ABC456")
+    );
+    assert_eq!(
+        state.selected_date("due", "due_input"),
+        Some(time::Date::from_calendar_date(2024, time::Month::May, 26).unwrap())
+    );
+}
+
+#[test]
+pub fn it_should_get_values_of_other_inputs_from_state() {
+    use slack_http_types::interactivity::State;
+
+    let state = r#"{
+        "values": {
+            "email": { "email_input": { "type": "email_text_input", "value": "oncall@example.com" } },
+            "link": { "link_input": { "type": "url_text_input", "value": "https://status.example.com" } },
+            "count": { "count_input": { "type": "number_input", "value": "2.5" } },
+            "start": { "start_input": { "type": "datetimepicker", "selected_date_time": 1716700028 } },
+            "notes": {
+                "notes_input": {
+                    "type": "rich_text_input",
+                    "rich_text_value": {
+                        "type": "rich_text",
+                        "elements": [{
+                            "type": "rich_text_section",
+                            "elements": [{ "type": "text", "text": "Rolled back" }]
+                        }]
+                    }
+                }
+            }
+        }
+    }"#;
+
+    let state = serde_json::from_str::<State>(state).unwrap();
+
+    assert_eq!(
+        state.text("email", "email_input"),
+        Some("oncall@example.com")
+    );
+    assert_eq!(
+        state.text("link", "link_input"),
+        Some("https://status.example.com")
+    );
+    assert_eq!(state.text("count", "count_input"), Some("2.5"));
+    assert_eq!(
+        state
+            .selected_date_time("start", "start_input")
+            .map(|start| start.unix_timestamp()),
+        Some(1716700028)
+    );
+    assert_eq!(
+        state.rich_text("notes", "notes_input").unwrap()["elements"][0]["elements"][0]["text"],
+        "Rolled back"
+    );
+}
+
+#[test]
+pub fn it_should_keep_unknown_payload_as_json() {
+    use slack_http_types::interactivity::Payload;
+
+    let body = "payload=%7B%22type%22%3A%22interactive_message%22%2C%22callback_id%22%3A%22wopr_game%22%7D";
+
+    match Payload::from_form(body.as_bytes()).unwrap() {
+        Payload::Unknown(value) => assert_eq!(value["callback_id"], "wopr_game"),
+        payload => panic!("unexpected payload {payload:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_fail_on_known_payload_that_does_not_match_its_type() {
+    use slack_http_types::interactivity::{Payload, PayloadError};
+
+    let body = "payload=%7B%22type%22%3A%22block_actions%22%2C%22user%22%3A%22U123ABC456%22%7D";
+
+    assert!(matches!(
+        Payload::from_form(body.as_bytes()),
+        Err(PayloadError::Deserialize(_))
+    ));
+}
+
+#[test]
+pub fn it_should_serialize_view_submission_responses() {
+    use slack_http_types::{
+        blocks::{Section, Text},
+        interactivity::{ViewErrors, ViewSubmissionResponse},
+        views::Modal,
+    };
+
+    let errors: ViewSubmissionResponse = ViewErrors::new()
+        .add("title".to_string(), "A title is required".to_string())
+        .into();
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        serde_json::json!({
+            "response_action": "errors",
+            "errors": { "title": "A title is required" }
+        })
+    );
+
+    let update = ViewSubmissionResponse::update(Modal::new(
+        "Thanks".to_string(),
+        vec![Section::new(Text::plain("Incident created".to_string())).into()],
+    ));
+
+    let update = serde_json::to_value(&update).unwrap();
+
+    assert_eq!(update["response_action"], "update");
+    assert_eq!(update["view"]["type"], "modal");
+    assert_eq!(update["view"]["title"]["text"], "Thanks");
+
+    assert_eq!(
+        serde_json::to_value(ViewSubmissionResponse::clear()).unwrap(),
+        serde_json::json!({ "response_action": "clear" })
+    );
+}
+
+#[test]
+pub fn it_should_deserialize_slash_commands() {
+    use slack_http_types::commands::{Arg, SlashCommand};
+
+    let body = "token=gIkuvaNzQIHg97ATvDxqgjtO&team_id=T0001&team_domain=example\
+        &enterprise_id=E0001&enterprise_name=Globular%20Construct%20Inc\
+        &channel_id=C2147483705&channel_name=test&user_id=U2147483697&user_name=Steve\
+        &command=%2Fweather&text=94070%20%3C%40U0LAN0Z89%7Cdan%3E%20%3C%23C0123%7Cgeneral%3E%20%22two%20words%22\
+        &response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2F1234%2F5678\
+        &trigger_id=13345224609.738474920.8088930838d88f008e0&api_app_id=A123456\
+        &is_enterprise_install=false";
+
+    let command = SlashCommand::from_form(body.as_bytes()).unwrap();
+
+    assert_eq!(command.command, "/weather");
+    assert_eq!(command.user_id.as_str(), "U2147483697");
+    assert_eq!(command.enterprise_id.as_deref(), Some("E0001"));
+    assert!(!command.is_enterprise_install);
+    assert_eq!(
+        command.args(),
+        vec![
+            Arg::Text("94070".to_string()),
+            Arg::User {
+                id: slack_http_types::user::Id("U0LAN0Z89".to_string()),
+                name: Some("dan".to_string()),
+            },
+            Arg::Conversation {
+                id: slack_http_types::conversation::Id("C0123".to_string()),
+                name: Some("general".to_string()),
+            },
+            Arg::Text("two words".to_string()),
+        ]
+    );
+}
+
+#[test]
+pub fn it_should_serialize_command_responses() {
+    use slack_http_types::{
+        blocks::{Section, Text},
+        commands::CommandResponse,
+    };
+
+    assert_eq!(
+        serde_json::to_value(CommandResponse::ephemeral("Nope".to_string())).unwrap(),
+        serde_json::json!({ "response_type": "ephemeral", "text": "Nope" })
+    );
+
+    let response =
+        CommandResponse::in_channel("Deployed".to_string())
+            .set_blocks(vec![
+                Section::new(Text::mrkdwn("*Deployed*".to_string())).into()
+            ]);
+
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(response["response_type"], "in_channel");
+    assert_eq!(response["blocks"][0]["type"], "section");
+}
+
+#[test]
+pub fn it_should_serialize_response_url_messages() {
+    use slack_http_types::{
+        chat::MessageOptions, commands::ResponseType, response_url::ResponseMessage,
+    };
+
+    let thread_ts = OffsetDateTime::from_unix_timestamp(1716700028).unwrap();
+
+    let message = ResponseMessage::new("Done".to_string())
+        .set_response_type(ResponseType::InChannel)
+        .set_replace_original(true)
+        .set_thread_ts(&thread_ts)
+        .set_opts(MessageOptions::new().set_username("deploybot".to_string()));
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        serde_json::json!({
+            "text": "Done",
+            "response_type": "in_channel",
+            "replace_original": true,
+            "thread_ts": "1716700028.000000",
+            "link_names": true,
+            "mrkdwn": true,
+            "username": "deploybot"
+        })
+    );
+}
+
+#[test]
+pub fn it_should_serialize_webhook_messages() {
+    use slack_http_types::{
+        chat::{Attachment, AttachmentField, MessageOptions},
+        webhooks::WebhookMessage,
+    };
+
+    let message = WebhookMessage::new("Build failed".to_string())
+        .set_attachments(vec![Attachment::new()
+            .set_color("danger".to_string())
+            .set_fields(vec![AttachmentField::new(
+                "Branch".to_string(),
+                "main".to_string(),
+                true,
+            )])])
+        .set_opts(MessageOptions::new().set_icon_emoji("rotating_light".to_string()));
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        serde_json::json!({
+            "text": "Build failed",
+            "attachments": [{
+                "color": "danger",
+                "fields": [{ "title": "Branch", "value": "main", "short": true }]
+            }],
+            "icon_emoji": ":rotating_light:",
+            "link_names": true,
+            "mrkdwn": true
+        })
+    );
+}
+
+#[test]
+pub fn it_should_build_block_suggestion_responses() {
+    use slack_http_types::{
+        blocks::{OptionGroup, SelectOption, Text},
+        interactivity::{filter_options, OptionsResponse},
+    };
+
+    let tickets = vec![
+        SelectOption::new(
+            Text::plain("Payments are slow".to_string()),
+            "OPS-1".to_string(),
+        ),
+        SelectOption::new(
+            Text::plain("pager rotation".to_string()),
+            "OPS-2".to_string(),
+        ),
+        SelectOption::new(Text::plain("Login fails".to_string()), "SEC-7".to_string()),
+    ];
+
+    let matches = filter_options(tickets.clone(), "Pa");
+    let values: Vec<_> = matches.iter().map(|option| option.value.as_str()).collect();
+
+    assert_eq!(values, vec!["OPS-1", "OPS-2"]);
+    assert_eq!(filter_options(tickets.clone(), "sec-").len(), 1);
+    assert_eq!(filter_options(tickets.clone(), "").len(), 3);
+
+    let options = serde_json::to_value(OptionsResponse::options(matches)).unwrap();
+
+    assert_eq!(options["options"][0]["text"]["text"], "Payments are slow");
+    assert_eq!(options["options"][1]["value"], "OPS-2");
+
+    let groups = OptionsResponse::option_groups(vec![
+        OptionGroup::new(
+            Text::plain("Ops".to_string()),
+            filter_options(tickets.clone(), "deploy"),
+        ),
+        OptionGroup::new(
+            Text::plain("Security".to_string()),
+            filter_options(tickets, "login"),
+        ),
+    ]);
+
+    let groups = serde_json::to_value(groups).unwrap();
+
+    assert_eq!(groups["option_groups"].as_array().unwrap().len(), 1);
+    assert_eq!(groups["option_groups"][0]["label"]["text"], "Security");
+    assert_eq!(groups["option_groups"][0]["options"][0]["value"], "SEC-7");
+
+    let many = (0..150).map(|i| SelectOption::new(Text::plain(format!("{i}")), format!("{i}")));
+
+    let many: Vec<_> = many.collect();
+
+    match OptionsResponse::options(many.clone()) {
+        OptionsResponse::Options { options } => assert_eq!(options.len(), 100),
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    let groups = (0..150).map(|i| OptionGroup::new(Text::plain(format!("{i}")), many.clone()));
+
+    match OptionsResponse::option_groups(groups.collect()) {
+        OptionsResponse::OptionGroups { option_groups } => {
+            assert_eq!(option_groups.len(), 100);
+            assert!(option_groups.iter().all(|group| group.options.len() == 100));
+        }
+        response => panic!("unexpected response {response:?}"),
+    }
+}
+
+#[test]
+pub fn it_should_cap_search_count() {
+    use slack_http_types::{page::Limit, search::SearchOptions};
+
+    let count = |opts: SearchOptions| {
+        opts.query_params(1)
+            .into_iter()
+            .find(|(name, _)| *name == "count")
+            .map(|(_, count)| count)
+    };
+
+    let opts = SearchOptions::new("deploy".to_string()).set_limit(Limit::new(500).unwrap());
+
+    assert_eq!(opts.limit.get(), 100);
+    assert_eq!(count(opts), Some("100".to_string()));
+
+    let opts = SearchOptions::new("deploy".to_string()).set_limit(Limit::new(20).unwrap());
+
+    assert_eq!(count(opts), Some("20".to_string()));
+}
@@ -0,0 +1,117 @@
+use slack_http::{client::AuthClient, dnd, oauth::AccessToken};
+use slack_http_types::team;
+
+pub struct TestEnv {
+    pub authed_bot_client: AuthClient,
+    pub authed_user_client: AuthClient,
+    pub invalid_bot_client: AuthClient,
+    pub invalid_user_client: AuthClient,
+    pub team_id: team::Id,
+}
+
+fn setup() -> TestEnv {
+    let bat = AccessToken(std::env::var("SLACK_BOT_ACCESS_TOKEN").unwrap());
+    let uat = AccessToken(std::env::var("SLACK_USER_ACCESS_TOKEN").unwrap());
+    let team_id = team::Id(std::env::var("SLACK_TEAM_ID").unwrap());
+
+    let authed_bot_client = slack_http::client::AuthClient::new(bat).unwrap();
+    let authed_user_client = slack_http::client::AuthClient::new(uat).unwrap();
+
+    TestEnv {
+        invalid_bot_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        invalid_user_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        authed_bot_client,
+        authed_user_client,
+        team_id,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// dnd.info
+
+#[tokio::test]
+async fn it_should_get_own_dnd_info() {
+    let test_env = setup();
+
+    let status = dnd::info(&test_env.authed_user_client, None).await.unwrap();
+
+    assert!(status.snooze_enabled.is_some());
+}
+
+#[tokio::test]
+async fn it_should_parse_dnd_info_error() {
+    let test_env = setup();
+
+    let err = dnd::info(&test_env.invalid_user_client, None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth");
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// dnd.teamInfo
+
+#[tokio::test]
+async fn it_should_get_team_dnd_info() {
+    let test_env = setup();
+
+    let page = slack_http::user::list(
+        &test_env.authed_user_client,
+        &test_env.team_id,
+        &slack_http::Cursor(None),
+        &slack_http::Limit::default(),
+    )
+    .await
+    .unwrap();
+
+    let user_ids: Vec<_> = page
+        .results
+        .into_iter()
+        .filter(|u| !u.is_bot && u.id.as_str() != "USLACKBOT")
+        .map(|u| u.id)
+        .collect();
+
+    let statuses = dnd::team_info(&test_env.authed_bot_client, &user_ids)
+        .await
+        .unwrap();
+
+    for user_id in user_ids.iter() {
+        assert!(statuses.contains_key(user_id))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// dnd.setSnooze, dnd.endSnooze
+
+#[tokio::test]
+async fn it_should_snooze_and_end_snooze() {
+    let test_env = setup();
+
+    let snooze = dnd::set_snooze(&test_env.authed_user_client, 5)
+        .await
+        .unwrap();
+
+    assert!(snooze.snooze_enabled);
+
+    let status = dnd::end_snooze(&test_env.authed_user_client).await.unwrap();
+
+    assert_eq!(status.snooze_enabled, Some(false));
+}
+
+#[tokio::test]
+async fn it_should_parse_end_dnd_error() {
+    let test_env = setup();
+
+    let err = dnd::end_dnd(&test_env.invalid_user_client)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth");
+}
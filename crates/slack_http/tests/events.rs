@@ -0,0 +1,167 @@
+use std::sync::{Arc, Mutex};
+
+use http::{HeaderMap, StatusCode};
+use ring::hmac;
+use slack_http::events::{Dispatcher, MessageEvent};
+
+const SIGNING_SECRET: &[u8] = b"8f742231b10e8888abcd99yyyzzz85a5";
+
+fn signed_headers(body: &str) -> HeaderMap {
+    let timestamp = time::OffsetDateTime::now_utc().unix_timestamp().to_string();
+    let key = hmac::Key::new(hmac::HMAC_SHA256, SIGNING_SECRET);
+    let digest = hmac::sign(&key, format!("v0:{timestamp}:{body}").as_bytes());
+
+    let signature: String = digest
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-slack-request-timestamp", timestamp.parse().unwrap());
+    headers.insert(
+        "x-slack-signature",
+        format!("v0={signature}").parse().unwrap(),
+    );
+    headers
+}
+
+fn event_callback(event: &str) -> String {
+    format!(
+        r#"{{
+            "token": "XXYYZZ",
+            "team_id": "T123ABC456",
+            "api_app_id": "A123ABC456",
+            "event": {event},
+            "type": "event_callback",
+            "event_id": "Ev123ABC456",
+            "event_time": 1515449522,
+            "authorizations": []
+        }}"#
+    )
+}
+
+#[tokio::test]
+async fn it_should_answer_url_verification() {
+    let body = r#"{"token":"XXYYZZ","challenge":"3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P","type":"url_verification"}"#;
+
+    let res = Dispatcher::new(SIGNING_SECRET)
+        .dispatch(&signed_headers(body), body.as_bytes())
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.body(),
+        "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+    );
+}
+
+#[tokio::test]
+async fn it_should_run_handler_for_event_type() {
+    let mentions = Arc::new(Mutex::new(Vec::new()));
+    let messages = Arc::new(Mutex::new(0));
+
+    let dispatcher = {
+        let mentions = mentions.clone();
+        let messages = messages.clone();
+
+        Dispatcher::new(SIGNING_SECRET)
+            .on_app_mention(move |context, mention| {
+                let mentions = mentions.clone();
+
+                async move {
+                    assert_eq!(context.event_id.as_str(), "Ev123ABC456");
+                    mentions.lock().unwrap().push(mention.text);
+                }
+            })
+            .on_message(move |_, message| {
+                let messages = messages.clone();
+
+                async move {
+                    assert!(matches!(message, MessageEvent::Message(_)));
+                    *messages.lock().unwrap() += 1;
+                }
+            })
+    };
+
+    let body = event_callback(
+        r#"{
+            "type": "app_mention",
+            "user": "U123ABC456",
+            "text": "<@U0LAN0Z89> deploy status?",
+            "ts": "1515449522.000016",
+            "channel": "C123ABC456",
+            "event_ts": "1515449522.000016"
+        }"#,
+    );
+
+    let res = dispatcher
+        .dispatch(&signed_headers(&body), body.as_bytes())
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(*mentions.lock().unwrap(), ["<@U0LAN0Z89> deploy status?"]);
+    assert_eq!(*messages.lock().unwrap(), 0);
+}
+
+#[tokio::test]
+async fn it_should_acknowledge_event_without_handler() {
+    let body = event_callback(
+        r#"{
+            "type": "emoji_changed",
+            "subtype": "remove",
+            "names": ["picard_facepalm"],
+            "event_ts": "1361482916.000004"
+        }"#,
+    );
+
+    let res = Dispatcher::new(SIGNING_SECRET)
+        .dispatch(&signed_headers(&body), body.as_bytes())
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(res.body().is_empty());
+}
+
+#[tokio::test]
+async fn it_should_acknowledge_verified_body_that_does_not_parse() {
+    for body in [
+        r#"{"type":"team_migration_started","team_id":"T123ABC456"}"#,
+        "not json at all",
+    ] {
+        let res = Dispatcher::new(SIGNING_SECRET)
+            .dispatch(&signed_headers(body), body.as_bytes())
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn it_should_reject_bad_signature() {
+    let body = r#"{"token":"XXYYZZ","challenge":"abc","type":"url_verification"}"#;
+    let mut headers = signed_headers(body);
+    headers.insert(
+        "x-slack-signature",
+        "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503"
+            .parse()
+            .unwrap(),
+    );
+
+    let res = Dispatcher::new(SIGNING_SECRET)
+        .dispatch(&headers, body.as_bytes())
+        .await;
+
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn it_should_reject_missing_headers() {
+    let body = r#"{"token":"XXYYZZ","challenge":"abc","type":"url_verification"}"#;
+
+    let res = Dispatcher::new(SIGNING_SECRET)
+        .dispatch(&HeaderMap::new(), body.as_bytes())
+        .await;
+
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
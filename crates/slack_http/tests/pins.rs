@@ -0,0 +1,99 @@
+use slack_http::oauth::AccessToken;
+use slack_http::pins::{self, PinnedItem};
+use slack_http::{chat::MessageOptions, client::AuthClient, conversation, team, Cursor};
+
+pub struct TestEnv {
+    pub authed_bot_client: AuthClient,
+    pub authed_user_client: AuthClient,
+    pub invalid_bot_client: AuthClient,
+    pub invalid_user_client: AuthClient,
+    pub team_id: team::Id,
+}
+
+fn setup() -> TestEnv {
+    let bat = AccessToken(std::env::var("SLACK_BOT_ACCESS_TOKEN").unwrap());
+    let uat = AccessToken(std::env::var("SLACK_USER_ACCESS_TOKEN").unwrap());
+    let team_id = team::Id(std::env::var("SLACK_TEAM_ID").unwrap());
+
+    let authed_bot_client = slack_http::client::AuthClient::new(bat).unwrap();
+    let authed_user_client = slack_http::client::AuthClient::new(uat).unwrap();
+
+    TestEnv {
+        invalid_bot_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        invalid_user_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        authed_bot_client,
+        authed_user_client,
+        team_id,
+    }
+}
+
+async fn find_test_channel(test_env: &TestEnv) -> conversation::Id {
+    let channels = slack_http::conversation::list(
+        &test_env.authed_user_client,
+        &test_env.team_id,
+        &Cursor(None),
+        Default::default(),
+    )
+    .await
+    .unwrap();
+
+    channels
+        .results
+        .into_iter()
+        .find(|c| c.name == "test_pins")
+        .unwrap()
+        .id
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// pins.add, pins.list, pins.remove
+
+#[tokio::test]
+async fn it_should_pin_list_and_unpin_message() {
+    let test_env = setup();
+    let channel_id = find_test_channel(&test_env).await;
+
+    let message = slack_http::chat::post_message(
+        &test_env.authed_bot_client,
+        &channel_id,
+        "Incident summary",
+        &MessageOptions::new(),
+    )
+    .await
+    .unwrap();
+
+    pins::add(&test_env.authed_bot_client, &channel_id, &message.timestamp)
+        .await
+        .unwrap();
+
+    let items = pins::list(&test_env.authed_bot_client, &channel_id)
+        .await
+        .unwrap();
+
+    assert!(items.iter().any(|item| match item {
+        PinnedItem::Message { message: m, .. } => m.timestamp == message.timestamp,
+        PinnedItem::File { .. } | PinnedItem::Unknown(_) => false,
+    }));
+
+    pins::remove(&test_env.authed_bot_client, &channel_id, &message.timestamp)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_parse_list_pins_error() {
+    let test_env = setup();
+    let channel_id = find_test_channel(&test_env).await;
+
+    let err = pins::list(&test_env.invalid_bot_client, &channel_id)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
@@ -0,0 +1,152 @@
+use slack_http::oauth::AccessToken;
+use slack_http::reactions::{self, Item, Name, ReactedItem};
+use slack_http::{chat::MessageOptions, client::AuthClient, team, Cursor, Limit};
+pub struct TestEnv {
+    pub authed_bot_client: AuthClient,
+    pub authed_user_client: AuthClient,
+    pub invalid_bot_client: AuthClient,
+    pub invalid_user_client: AuthClient,
+    pub team_id: team::Id,
+}
+
+fn setup() -> TestEnv {
+    let bat = AccessToken(std::env::var("SLACK_BOT_ACCESS_TOKEN").unwrap());
+    let uat = AccessToken(std::env::var("SLACK_USER_ACCESS_TOKEN").unwrap());
+    let team_id = team::Id(std::env::var("SLACK_TEAM_ID").unwrap());
+
+    let authed_bot_client = slack_http::client::AuthClient::new(bat).unwrap();
+    let authed_user_client = slack_http::client::AuthClient::new(uat).unwrap();
+
+    TestEnv {
+        invalid_bot_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        invalid_user_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        authed_bot_client,
+        authed_user_client,
+        team_id,
+    }
+}
+
+async fn post_test_message(
+    test_env: &TestEnv,
+) -> (slack_http::conversation::Id, slack_http::chat::Message) {
+    let channels = slack_http::conversation::list(
+        &test_env.authed_user_client,
+        &test_env.team_id,
+        &Cursor(None),
+        Default::default(),
+    )
+    .await
+    .unwrap();
+
+    let test_channel = channels
+        .results
+        .into_iter()
+        .find(|c| c.name == "test_post_message")
+        .unwrap();
+
+    let message = slack_http::chat::post_message(
+        &test_env.authed_bot_client,
+        &test_channel.id,
+        "React to me!",
+        &MessageOptions::new(),
+    )
+    .await
+    .unwrap();
+
+    (test_channel.id, message)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// reactions.add, reactions.get, reactions.remove
+
+#[tokio::test]
+async fn it_should_add_get_and_remove_reaction() {
+    let test_env = setup();
+    let (channel_id, message) = post_test_message(&test_env).await;
+    let name = Name::new(":white_check_mark:");
+
+    reactions::add(
+        &test_env.authed_bot_client,
+        &channel_id,
+        &message.timestamp,
+        &name,
+    )
+    .await
+    .unwrap();
+
+    let item = Item::Message {
+        channel: channel_id,
+        timestamp: message.timestamp,
+    };
+
+    let reacted = reactions::get(&test_env.authed_bot_client, &item)
+        .await
+        .unwrap();
+
+    match reacted {
+        ReactedItem::Message { message, .. } => {
+            assert!(message.reactions.iter().any(|r| r.name == name))
+        }
+        ReactedItem::File { .. } => panic!("expected a message"),
+    }
+
+    reactions::remove(&test_env.authed_bot_client, &item, &name)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_parse_add_reaction_error() {
+    let test_env = setup();
+    let (channel_id, message) = post_test_message(&test_env).await;
+
+    let err = reactions::add(
+        &test_env.invalid_bot_client,
+        &channel_id,
+        &message.timestamp,
+        &Name::new("x"),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// reactions.list
+
+#[tokio::test]
+async fn it_should_list_reactions() {
+    let test_env = setup();
+
+    let _page = reactions::list(
+        &test_env.authed_user_client,
+        None,
+        &Cursor(None),
+        &Limit::default(),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_parse_list_reactions_error() {
+    let test_env = setup();
+
+    let err = reactions::list(
+        &test_env.invalid_user_client,
+        None,
+        &Cursor(None),
+        &Limit::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
@@ -0,0 +1,105 @@
+use slack_http::oauth::AccessToken;
+use slack_http::reminders::{self, NewReminder, When};
+use slack_http::{client::AuthClient, team};
+
+pub struct TestEnv {
+    pub authed_bot_client: AuthClient,
+    pub authed_user_client: AuthClient,
+    pub invalid_bot_client: AuthClient,
+    pub invalid_user_client: AuthClient,
+    pub team_id: team::Id,
+}
+
+fn setup() -> TestEnv {
+    let bat = AccessToken(std::env::var("SLACK_BOT_ACCESS_TOKEN").unwrap());
+    let uat = AccessToken(std::env::var("SLACK_USER_ACCESS_TOKEN").unwrap());
+    let team_id = team::Id(std::env::var("SLACK_TEAM_ID").unwrap());
+
+    let authed_bot_client = slack_http::client::AuthClient::new(bat).unwrap();
+    let authed_user_client = slack_http::client::AuthClient::new(uat).unwrap();
+
+    TestEnv {
+        invalid_bot_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        invalid_user_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        authed_bot_client,
+        authed_user_client,
+        team_id,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// reminders.add, reminders.info, reminders.list, reminders.delete
+
+#[tokio::test]
+async fn it_should_add_and_delete_reminder() {
+    let test_env = setup();
+
+    let new_reminder = NewReminder::new(
+        "hand off on-call".to_string(),
+        When::Natural("in 1 hour".to_string()),
+    );
+
+    let reminder = reminders::add(&test_env.authed_user_client, &new_reminder)
+        .await
+        .unwrap();
+
+    assert_eq!(reminder.text, "hand off on-call");
+    assert!(!reminder.recurring);
+
+    let info = reminders::info(&test_env.authed_user_client, &reminder.id)
+        .await
+        .unwrap();
+
+    assert_eq!(info.id, reminder.id);
+
+    let all = reminders::list(&test_env.authed_user_client).await.unwrap();
+
+    assert!(all.iter().any(|r| r.id == reminder.id));
+
+    reminders::delete(&test_env.authed_user_client, &reminder.id)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_add_and_complete_reminder() {
+    let test_env = setup();
+    let in_an_hour = time::OffsetDateTime::now_utc() + time::Duration::HOUR;
+
+    let new_reminder = NewReminder::new("review handoff".to_string(), When::At(in_an_hour));
+
+    let reminder = reminders::add(&test_env.authed_user_client, &new_reminder)
+        .await
+        .unwrap();
+
+    reminders::complete(&test_env.authed_user_client, &reminder.id)
+        .await
+        .unwrap();
+
+    let info = reminders::info(&test_env.authed_user_client, &reminder.id)
+        .await
+        .unwrap();
+
+    assert!(info.complete_ts.is_some());
+
+    reminders::delete(&test_env.authed_user_client, &reminder.id)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_parse_list_reminders_error() {
+    let test_env = setup();
+
+    let err = reminders::list(&test_env.invalid_user_client)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
@@ -0,0 +1,86 @@
+use slack_http::{
+    client::BasicClient,
+    response_url::{self, ResponseMessage, ResponseUrl, MAX_USES},
+};
+use time::{Duration, OffsetDateTime};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use url::Url;
+
+// Nothing listens here, so requests that get through fail with
+// `Error::Request` without leaving the machine.
+const UNREACHABLE: &str = "http://127.0.0.1:9/commands/1234/5678";
+
+/// Serves a single request with the given JSON body and returns the URL to
+/// post to
+async fn serve_once(body: &'static str) -> Url {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0; 8192];
+        let _ = stream.read(&mut buf).await.unwrap();
+
+        let res = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(res.as_bytes()).await.unwrap();
+    });
+
+    Url::parse(&format!("http://{addr}/commands/1234/5678")).unwrap()
+}
+
+#[tokio::test]
+async fn it_should_check_ok_in_json_responses() {
+    let client = BasicClient::new().unwrap();
+
+    let url = ResponseUrl::new(serve_once(r#"{"ok":true}"#).await);
+    response_url::delete(&client, &url).await.unwrap();
+
+    let url = ResponseUrl::new(serve_once(r#"{"ok":false}"#).await);
+    let err = response_url::delete(&client, &url).await.unwrap_err();
+
+    assert_eq!(err.get_slack_error(), Some(r#"{"ok":false}"#));
+
+    let url = ResponseUrl::new(serve_once(r#"{"ok":false,"error":"no_text"}"#).await);
+    let err = response_url::delete(&client, &url).await.unwrap_err();
+
+    assert_eq!(err.get_slack_error(), Some("no_text"));
+}
+
+#[tokio::test]
+async fn it_should_refuse_an_expired_response_url() {
+    let client = BasicClient::new().unwrap();
+    let url = ResponseUrl::received_at(
+        Url::parse(UNREACHABLE).unwrap(),
+        OffsetDateTime::now_utc() - Duration::minutes(31),
+    );
+
+    let err = response_url::send(&client, &url, &ResponseMessage::new("late".to_string()))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error(), Some("expired_url"));
+    assert_eq!(url.remaining_uses(), MAX_USES);
+}
+
+#[tokio::test]
+async fn it_should_refuse_a_used_up_response_url() {
+    let client = BasicClient::new().unwrap();
+    let url = ResponseUrl::new(Url::parse(UNREACHABLE).unwrap());
+
+    for _ in 0..MAX_USES {
+        let err = response_url::delete(&client, &url).await.unwrap_err();
+        assert!(err.get_slack_error().is_none());
+    }
+
+    assert_eq!(url.remaining_uses(), 0);
+
+    let err = response_url::delete(&client, &url).await.unwrap_err();
+
+    assert_eq!(err.get_slack_error(), Some("used_url"));
+}
@@ -0,0 +1,124 @@
+use slack_http::{
+    client::AuthClient,
+    oauth::AccessToken,
+    search::{self, SearchOptions, Sort, SortDirection},
+};
+use slack_http_types::team;
+
+pub struct TestEnv {
+    pub authed_bot_client: AuthClient,
+    pub authed_user_client: AuthClient,
+    pub invalid_bot_client: AuthClient,
+    pub invalid_user_client: AuthClient,
+    pub team_id: team::Id,
+}
+
+fn setup() -> TestEnv {
+    let bat = AccessToken(std::env::var("SLACK_BOT_ACCESS_TOKEN").unwrap());
+    let uat = AccessToken(std::env::var("SLACK_USER_ACCESS_TOKEN").unwrap());
+    let team_id = team::Id(std::env::var("SLACK_TEAM_ID").unwrap());
+
+    let authed_bot_client = slack_http::client::AuthClient::new(bat).unwrap();
+    let authed_user_client = slack_http::client::AuthClient::new(uat).unwrap();
+
+    TestEnv {
+        invalid_bot_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        invalid_user_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        authed_bot_client,
+        authed_user_client,
+        team_id,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// search.messages
+
+#[tokio::test]
+async fn it_should_search_messages() {
+    let test_env = setup();
+
+    let page = search::messages(
+        &test_env.authed_user_client,
+        1,
+        &SearchOptions::new("hello".to_string())
+            .set_sort(Sort::Timestamp, SortDirection::Descending)
+            .set_highlight(true),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(page.paging().page, 1);
+}
+
+#[tokio::test]
+async fn it_should_parse_search_messages_error() {
+    let test_env = setup();
+
+    let err = search::messages(
+        &test_env.invalid_user_client,
+        1,
+        &SearchOptions::new("hello".to_string()),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// search.files
+
+#[tokio::test]
+async fn it_should_search_files() {
+    let test_env = setup();
+
+    let page = search::files(
+        &test_env.authed_user_client,
+        1,
+        &SearchOptions::new("artifact".to_string()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(page.paging().page, 1);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// search.all
+
+#[tokio::test]
+async fn it_should_search_all() {
+    let test_env = setup();
+
+    let results = search::all(
+        &test_env.authed_user_client,
+        1,
+        &SearchOptions::new("hello".to_string()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.messages.paging().page, 1);
+    assert_eq!(results.files.paging().page, 1);
+}
+
+#[tokio::test]
+async fn it_should_not_search_with_bot_token() {
+    let test_env = setup();
+
+    let err = search::all(
+        &test_env.authed_bot_client,
+        1,
+        &SearchOptions::new("hello".to_string()),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "not_allowed_token_type")
+}
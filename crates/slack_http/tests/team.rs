@@ -1,5 +1,6 @@
 use slack_http::oauth::AccessToken;
 use slack_http::{client::AuthClient, team};
+use slack_http::{Cursor, Limit};
 
 pub struct TestEnv {
     pub authed_bot_client: AuthClient,
@@ -55,3 +56,18 @@ async fn it_should_parse_get_team_info_error() {
 
     assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
 }
+
+#[tokio::test]
+async fn it_should_parse_list_external_teams_error() {
+    let test_env = setup();
+
+    let err = team::list_external_teams(
+        &test_env.invalid_bot_client,
+        &Cursor(None),
+        &Limit::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
@@ -1,5 +1,5 @@
 use slack_http::oauth::AccessToken;
-use slack_http::{client::AuthClient, team, user};
+use slack_http::{client::AuthClient, team, user, user::ProfileUpdate};
 use slack_http::{Cursor, Limit};
 
 pub struct TestEnv {
@@ -71,3 +71,180 @@ async fn it_should_parse_list_users_error() {
 
     assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
 }
+
+#[tokio::test]
+async fn it_should_get_user_info() {
+    let test_env = setup();
+
+    let page = user::list(
+        &test_env.authed_user_client,
+        &test_env.team_id,
+        &Cursor(None),
+        &Limit::default(),
+    )
+    .await
+    .unwrap();
+
+    let owner = page
+        .results
+        .iter()
+        .find(|u| u.profile.display_name.as_deref() == Some("OWNER"))
+        .unwrap();
+
+    let user = user::info(&test_env.authed_bot_client, &owner.id)
+        .await
+        .unwrap();
+
+    assert_eq!(user.id, owner.id);
+}
+
+#[tokio::test]
+async fn it_should_parse_get_user_info_error() {
+    let test_env = setup();
+
+    let err = user::info(
+        &test_env.invalid_bot_client,
+        &user::Id("U0000000000".to_string()),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+#[tokio::test]
+async fn it_should_lookup_user_by_email() {
+    let test_env = setup();
+
+    let page = user::list(
+        &test_env.authed_user_client,
+        &test_env.team_id,
+        &Cursor(None),
+        &Limit::default(),
+    )
+    .await
+    .unwrap();
+
+    let owner = page
+        .results
+        .iter()
+        .find(|u| u.profile.display_name.as_deref() == Some("OWNER"))
+        .unwrap();
+
+    let user = user::lookup_by_email(
+        &test_env.authed_bot_client,
+        owner.profile.email.as_ref().unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(user.id, owner.id);
+}
+
+#[tokio::test]
+async fn it_should_get_own_profile() {
+    let test_env = setup();
+
+    let profile = user::profile_get(&test_env.authed_user_client, None)
+        .await
+        .unwrap();
+
+    assert_eq!(profile.display_name.as_deref(), Some("OWNER"));
+}
+
+#[tokio::test]
+async fn it_should_set_own_status() {
+    let test_env = setup();
+    let update = ProfileUpdate::new().set_status("testing".to_string(), "taco".to_string(), None);
+
+    let profile = user::profile_set(&test_env.authed_user_client, None, &update)
+        .await
+        .unwrap();
+
+    assert_eq!(profile.status_text.as_deref(), Some("testing"));
+    assert_eq!(profile.status_emoji.as_deref(), Some(":taco:"));
+
+    let profile = user::profile_set(
+        &test_env.authed_user_client,
+        None,
+        &ProfileUpdate::new().clear_status(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(profile.status_text.as_deref().unwrap_or_default(), "");
+}
+
+#[tokio::test]
+async fn it_should_parse_set_profile_error() {
+    let test_env = setup();
+    let update = ProfileUpdate::new().set_title("nope".to_string());
+
+    let err = user::profile_set(&test_env.invalid_user_client, None, &update)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+#[tokio::test]
+async fn it_should_get_and_set_own_presence() {
+    let test_env = setup();
+
+    user::set_presence(&test_env.authed_user_client, user::SetPresence::Away)
+        .await
+        .unwrap();
+
+    let presence = user::get_presence(&test_env.authed_user_client, None)
+        .await
+        .unwrap();
+
+    assert_eq!(presence.presence, user::PresenceStatus::Away);
+    assert_eq!(presence.manual_away, Some(true));
+
+    user::set_presence(&test_env.authed_user_client, user::SetPresence::Auto)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_parse_get_presence_error() {
+    let test_env = setup();
+
+    let err = user::get_presence(&test_env.invalid_user_client, None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+#[tokio::test]
+async fn it_should_parse_set_photo_error() {
+    let test_env = setup();
+
+    let err = user::set_photo(
+        &test_env.invalid_user_client,
+        vec![0; 16],
+        "photo.png",
+        Some(user::PhotoCrop {
+            x: 0,
+            y: 0,
+            width: 4,
+        }),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+#[tokio::test]
+async fn it_should_parse_delete_photo_error() {
+    let test_env = setup();
+
+    let err = user::delete_photo(&test_env.invalid_user_client)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
@@ -0,0 +1,138 @@
+use slack_http::{
+    client::AuthClient,
+    oauth::AccessToken,
+    usergroups::{self, ListOptions, NewUsergroup, UsergroupUpdate},
+    Cursor, Limit,
+};
+use slack_http_types::{team, user};
+
+pub struct TestEnv {
+    pub authed_bot_client: AuthClient,
+    pub authed_user_client: AuthClient,
+    pub invalid_bot_client: AuthClient,
+    pub invalid_user_client: AuthClient,
+    pub team_id: team::Id,
+}
+
+fn setup() -> TestEnv {
+    let bat = AccessToken(std::env::var("SLACK_BOT_ACCESS_TOKEN").unwrap());
+    let uat = AccessToken(std::env::var("SLACK_USER_ACCESS_TOKEN").unwrap());
+    let team_id = team::Id(std::env::var("SLACK_TEAM_ID").unwrap());
+
+    let authed_bot_client = slack_http::client::AuthClient::new(bat).unwrap();
+    let authed_user_client = slack_http::client::AuthClient::new(uat).unwrap();
+
+    TestEnv {
+        invalid_bot_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        invalid_user_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        authed_bot_client,
+        authed_user_client,
+        team_id,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// usergroups.create, usergroups.update, usergroups.users.update,
+// usergroups.users.list, usergroups.list, usergroups.disable, usergroups.enable
+
+#[tokio::test]
+async fn it_should_manage_usergroup() {
+    let test_env = setup();
+    let suffix = time::OffsetDateTime::now_utc().unix_timestamp();
+
+    let usergroup = usergroups::create(
+        &test_env.authed_user_client,
+        &NewUsergroup::new(format!("On-call {suffix}"))
+            .set_handle(format!("oncall-{suffix}"))
+            .set_description("Whoever is on call".to_string()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(usergroup.handle, format!("oncall-{suffix}"));
+
+    let usergroup = usergroups::update(
+        &test_env.authed_user_client,
+        &usergroup.id,
+        &UsergroupUpdate::new().set_description("Whoever is on call this shift".to_string()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(usergroup.description, "Whoever is on call this shift");
+
+    let page = slack_http::user::list(
+        &test_env.authed_user_client,
+        &test_env.team_id,
+        &Cursor(None),
+        &Limit::default(),
+    )
+    .await
+    .unwrap();
+
+    let user_ids: Vec<user::Id> = page
+        .results
+        .into_iter()
+        .filter(|u| !u.is_bot && u.id.0.as_str() != "USLACKBOT")
+        .map(|u| u.id)
+        .take(1)
+        .collect();
+
+    let usergroup =
+        usergroups::users_update(&test_env.authed_user_client, &usergroup.id, &user_ids)
+            .await
+            .unwrap();
+
+    assert_eq!(usergroup.user_count, Some(1));
+
+    let users = usergroups::users_list(&test_env.authed_user_client, &usergroup.id, false)
+        .await
+        .unwrap();
+
+    assert_eq!(users, user_ids);
+
+    let usergroup = usergroups::disable(&test_env.authed_user_client, &usergroup.id)
+        .await
+        .unwrap();
+
+    assert!(usergroup.date_delete.is_some());
+
+    let list = usergroups::list(
+        &test_env.authed_user_client,
+        &ListOptions::new()
+            .include_disabled(true)
+            .include_users(true),
+    )
+    .await
+    .unwrap();
+
+    assert!(list.iter().any(|u| u.id == usergroup.id));
+
+    let usergroup = usergroups::enable(&test_env.authed_user_client, &usergroup.id)
+        .await
+        .unwrap();
+
+    assert!(usergroup.date_delete.is_none());
+
+    // User groups can't be deleted, so leave it disabled.
+    usergroups::disable(&test_env.authed_user_client, &usergroup.id)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_parse_list_usergroups_error() {
+    let test_env = setup();
+
+    let err = usergroups::list(&test_env.invalid_user_client, &ListOptions::new())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
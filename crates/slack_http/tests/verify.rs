@@ -0,0 +1,216 @@
+use http::HeaderMap;
+use ring::hmac;
+use slack_http::{verify, verify_request, VerificationError, Verifier};
+use time::{Duration, OffsetDateTime};
+
+const SIGNING_SECRET: &[u8] = b"8f742231b10e8888abcd99yyyzzz85a5";
+
+fn sign(timestamp: i64, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, SIGNING_SECRET);
+    let mut basestring = format!("v0:{timestamp}:").into_bytes();
+    basestring.extend_from_slice(body);
+
+    hmac::sign(&key, &basestring)
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn headers(timestamp: i64, signature: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-slack-request-timestamp", timestamp.into());
+    headers.insert("x-slack-signature", signature.parse().unwrap());
+    headers
+}
+
+fn now() -> i64 {
+    time::OffsetDateTime::now_utc().unix_timestamp()
+}
+
+#[test]
+fn it_should_verify_request_from_headers() {
+    let body = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&command=%2Fhelpdesk";
+    let timestamp = now();
+    let signature = sign(timestamp, body);
+
+    verify_request(
+        SIGNING_SECRET,
+        &headers(timestamp, &format!("v0={signature}")),
+        body,
+    )
+    .unwrap();
+
+    // `verify` takes the signature with or without its prefix
+    let body = std::str::from_utf8(body).unwrap();
+    let timestamp = timestamp.to_string();
+
+    verify(SIGNING_SECRET, &timestamp, &signature, body).unwrap();
+    verify(SIGNING_SECRET, &timestamp, &format!("v0={signature}"), body).unwrap();
+}
+
+#[test]
+fn it_should_verify_non_utf8_body() {
+    let body = [0x7b, 0xff, 0xfe, 0x7d];
+    let timestamp = now();
+    let signature = format!("v0={}", sign(timestamp, &body));
+
+    verify_request(SIGNING_SECRET, &headers(timestamp, &signature), &body).unwrap();
+}
+
+#[test]
+fn it_should_reject_tampered_body() {
+    let timestamp = now();
+    let signature = format!("v0={}", sign(timestamp, b"text=hello"));
+
+    let err =
+        verify_request(SIGNING_SECRET, &headers(timestamp, &signature), b"text=bye").unwrap_err();
+
+    assert!(matches!(err, VerificationError::DigestMismatch));
+}
+
+#[test]
+fn it_should_reject_missing_or_malformed_headers() {
+    let timestamp = now();
+    let signature = sign(timestamp, b"");
+
+    let mut missing_signature = HeaderMap::new();
+    missing_signature.insert("x-slack-request-timestamp", timestamp.into());
+
+    assert!(matches!(
+        verify_request(SIGNING_SECRET, &missing_signature, b""),
+        Err(VerificationError::MissingHeader("x-slack-signature"))
+    ));
+
+    assert!(matches!(
+        verify_request(SIGNING_SECRET, &HeaderMap::new(), b""),
+        Err(VerificationError::MissingHeader(
+            "x-slack-request-timestamp"
+        ))
+    ));
+
+    assert!(matches!(
+        verify_request(SIGNING_SECRET, &headers(timestamp, &signature), b""),
+        Err(VerificationError::UnsupportedVersion)
+    ));
+
+    assert!(matches!(
+        verify_request(SIGNING_SECRET, &headers(timestamp, "v0=nothex"), b""),
+        Err(VerificationError::SlackSignatureNotHex)
+    ));
+}
+
+#[test]
+fn it_should_reject_old_timestamp() {
+    let timestamp = now() - 60 * 6;
+    let signature = format!("v0={}", sign(timestamp, b""));
+
+    assert!(matches!(
+        verify_request(SIGNING_SECRET, &headers(timestamp, &signature), b""),
+        Err(VerificationError::TimestampTooOld)
+    ));
+}
+
+// 2024-05-26 05:07:08 UTC
+const PINNED: i64 = 1716700028;
+
+fn pinned_verifier() -> Verifier {
+    Verifier::new(SIGNING_SECRET).set_clock(|| OffsetDateTime::from_unix_timestamp(PINNED).unwrap())
+}
+
+#[test]
+fn it_should_verify_against_pinned_clock() {
+    let body = b"payload=%7B%7D";
+
+    for timestamp in [PINNED, PINNED - 60 * 5, PINNED + 60 * 5] {
+        let signature = format!("v0={}", sign(timestamp, body));
+
+        pinned_verifier()
+            .verify_request(&headers(timestamp, &signature), body)
+            .unwrap();
+    }
+}
+
+#[test]
+fn it_should_reject_stale_and_future_timestamps() {
+    let verifier = pinned_verifier();
+
+    let stale = PINNED - 60 * 5 - 1;
+    let signature = format!("v0={}", sign(stale, b""));
+
+    assert!(matches!(
+        verifier.verify_request(&headers(stale, &signature), b""),
+        Err(VerificationError::TimestampTooOld)
+    ));
+
+    let future = PINNED + 60 * 5 + 1;
+    let signature = format!("v0={}", sign(future, b""));
+
+    assert!(matches!(
+        verifier.verify_request(&headers(future, &signature), b""),
+        Err(VerificationError::TimestampInFuture)
+    ));
+}
+
+#[test]
+fn it_should_use_configured_tolerance() {
+    let verifier = pinned_verifier().set_tolerance(Duration::seconds(30));
+
+    let timestamp = PINNED - 31;
+    let signature = format!("v0={}", sign(timestamp, b""));
+
+    assert!(matches!(
+        verifier.verify_request(&headers(timestamp, &signature), b""),
+        Err(VerificationError::TimestampTooOld)
+    ));
+
+    let timestamp = PINNED + 30;
+    let signature = sign(timestamp, b"");
+
+    verifier
+        .verify(&timestamp.to_string(), &signature, b"")
+        .unwrap();
+}
+
+#[test]
+fn it_should_reject_signature_that_is_not_hex() {
+    let timestamp = now().to_string();
+
+    for signature in ["abc", "zz", "v0=0g", "+1", "é1"] {
+        assert!(matches!(
+            verify(SIGNING_SECRET, &timestamp, signature, ""),
+            Err(VerificationError::SlackSignatureNotHex)
+        ));
+    }
+
+    // Uppercase hex is still hex
+    let signature = sign(now(), b"").to_uppercase();
+
+    verify(SIGNING_SECRET, &timestamp, &signature, "").unwrap();
+}
+
+#[test]
+fn it_should_handle_extreme_tolerances() {
+    let timestamp = PINNED;
+    let signature = sign(timestamp, b"");
+
+    // A negative tolerance works like a positive one
+    pinned_verifier()
+        .set_tolerance(Duration::minutes(-5))
+        .verify(&timestamp.to_string(), &signature, b"")
+        .unwrap();
+
+    let stale = PINNED - 60 * 60 * 24 * 365 * 50;
+    let stale_signature = sign(stale, b"");
+
+    for tolerance in [Duration::MAX, Duration::MIN] {
+        let verifier = pinned_verifier().set_tolerance(tolerance);
+
+        verifier
+            .verify(&timestamp.to_string(), &signature, b"")
+            .unwrap();
+        verifier
+            .verify(&stale.to_string(), &stale_signature, b"")
+            .unwrap();
+    }
+}
@@ -0,0 +1,191 @@
+use slack_http::{
+    blocks::{Context, Header, Input, PlainTextInput, Section, Text},
+    client::AuthClient,
+    oauth::AccessToken,
+    views::{self, Home, Kind, Modal, TriggerId},
+    Cursor, Limit,
+};
+use slack_http_types::team;
+
+pub struct TestEnv {
+    pub authed_bot_client: AuthClient,
+    pub authed_user_client: AuthClient,
+    pub invalid_bot_client: AuthClient,
+    pub invalid_user_client: AuthClient,
+    pub team_id: team::Id,
+}
+
+fn setup() -> TestEnv {
+    let bat = AccessToken(std::env::var("SLACK_BOT_ACCESS_TOKEN").unwrap());
+    let uat = AccessToken(std::env::var("SLACK_USER_ACCESS_TOKEN").unwrap());
+    let team_id = team::Id(std::env::var("SLACK_TEAM_ID").unwrap());
+
+    let authed_bot_client = slack_http::client::AuthClient::new(bat).unwrap();
+    let authed_user_client = slack_http::client::AuthClient::new(uat).unwrap();
+
+    TestEnv {
+        invalid_bot_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        invalid_user_client: slack_http::client::AuthClient::new(AccessToken(
+            "HUHWHATTHISBE".to_string(),
+        ))
+        .unwrap(),
+        authed_bot_client,
+        authed_user_client,
+        team_id,
+    }
+}
+
+fn feedback_modal() -> Modal {
+    Modal::new(
+        "Feedback".to_string(),
+        vec![
+            Section::new(Text::mrkdwn("How did the *deploy* go?".to_string())).into(),
+            Input::new(
+                "feedback".to_string(),
+                "Notes".to_string(),
+                PlainTextInput::new("notes".to_string())
+                    .set_multiline(true)
+                    .into(),
+            )
+            .into(),
+        ],
+    )
+    .set_submit("Send".to_string())
+    .set_callback_id("deploy_feedback".to_string())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// views.open
+
+// A trigger ID only comes from a user interacting with the app, so the happy
+// path can't be tested here.
+#[tokio::test]
+async fn it_should_reject_expired_trigger_id() {
+    let test_env = setup();
+
+    let err = views::open(
+        &test_env.authed_bot_client,
+        &TriggerId("12345.98765.abcd2358fdea".to_string()),
+        &feedback_modal(),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_trigger_id")
+}
+
+#[tokio::test]
+async fn it_should_parse_open_view_error() {
+    let test_env = setup();
+
+    let err = views::open(
+        &test_env.invalid_bot_client,
+        &TriggerId("12345.98765.abcd2358fdea".to_string()),
+        &feedback_modal(),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// views.update
+
+#[tokio::test]
+async fn it_should_parse_update_view_error() {
+    let test_env = setup();
+
+    let err = views::update(
+        &test_env.invalid_bot_client,
+        &views::Id("V0000000000".to_string()),
+        &feedback_modal(),
+        Some("156772938.1827394"),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// views.publish
+
+#[tokio::test]
+async fn it_should_publish_home_and_detect_hash_conflict() {
+    let test_env = setup();
+
+    let page = slack_http::user::list(
+        &test_env.authed_bot_client,
+        &test_env.team_id,
+        &Cursor(None),
+        &Limit::default(),
+    )
+    .await
+    .unwrap();
+
+    let user = page
+        .results
+        .into_iter()
+        .find(|u| !u.is_bot && !u.deleted && u.id.0.as_str() != "USLACKBOT")
+        .unwrap();
+
+    let home = |greeting: String| {
+        Home::new(vec![
+            Header::new(greeting).into(),
+            Context::new(vec![Text::mrkdwn("Updated just now".to_string()).into()]).into(),
+        ])
+    };
+
+    let view = views::publish(
+        &test_env.authed_bot_client,
+        &user.id,
+        &home(format!("Hi {}", user.name)),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(view.kind, Kind::Home);
+
+    let stale_hash = view.hash.clone();
+
+    views::publish(
+        &test_env.authed_bot_client,
+        &user.id,
+        &home(format!("Hello {}", user.name)),
+        Some(&view.hash),
+    )
+    .await
+    .unwrap();
+
+    let err = views::publish(
+        &test_env.authed_bot_client,
+        &user.id,
+        &home(format!("Hey {}", user.name)),
+        Some(&stale_hash),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(err.is_hash_conflict());
+}
+
+#[tokio::test]
+async fn it_should_parse_publish_view_error() {
+    let test_env = setup();
+
+    let err = views::publish(
+        &test_env.invalid_bot_client,
+        &slack_http::user::Id("U0000000000".to_string()),
+        &Home::new(vec![Header::new("Hi".to_string()).into()]),
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.get_slack_error().unwrap(), "invalid_auth")
+}
@@ -0,0 +1,55 @@
+use slack_http::webhooks::{WebhookClient, WebhookMessage};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use url::Url;
+
+/// Serves a single request with the given status and plain text body and
+/// returns the URL to post to
+async fn serve_once(status: &'static str, body: &'static str) -> Url {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0; 8192];
+        let _ = stream.read(&mut buf).await.unwrap();
+
+        let res = format!(
+            "HTTP/1.1 {status}\r\ncontent-type: text/plain\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(res.as_bytes()).await.unwrap();
+    });
+
+    Url::parse(&format!("http://{addr}/services/T000/B000/XXXX")).unwrap()
+}
+
+#[tokio::test]
+async fn it_should_post_to_a_webhook() {
+    let url = serve_once("200 OK", "ok").await;
+    let client = WebhookClient::new(url).unwrap();
+
+    client.post_text("Deployed").await.unwrap();
+}
+
+#[tokio::test]
+async fn it_should_map_webhook_errors() {
+    let url = serve_once("410 Gone", "channel_is_archived").await;
+    let client = WebhookClient::new(url).unwrap();
+
+    let err = client
+        .post(&WebhookMessage::new("Deployed".to_string()))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.get_slack_error(), Some("channel_is_archived"));
+
+    let url = serve_once("400 Bad Request", "invalid_payload").await;
+    let client = WebhookClient::new(url).unwrap();
+
+    let err = client.post_text("").await.unwrap_err();
+
+    assert_eq!(err.get_slack_error(), Some("invalid_payload"));
+}
@@ -0,0 +1,1035 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::{conversation, user};
+
+// COMPOSITION OBJECTS
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    PlainText {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+/// An option in a select menu, checkbox group or radio button group
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SelectOption {
+    pub text: Text,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OptionGroup {
+    pub label: Text,
+    pub options: Vec<SelectOption>,
+}
+
+// BLOCKS
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Actions(Actions),
+    Context(Context),
+    Divider(Divider),
+    Header(Header),
+    Image(ImageBlock),
+    Input(Input),
+    Section(Section),
+    /// A block this crate doesn't know about, or that doesn't match its
+    /// type, kept as it was sent so it can be sent back unchanged
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Actions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    pub elements: Vec<Element>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Context {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    pub elements: Vec<ContextElement>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContextElement {
+    Image {
+        image_url: Url,
+        alt_text: String,
+    },
+    PlainText {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+    /// An element this crate doesn't know about, kept as it was sent
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Divider {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Header {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// Has to be plain text
+    pub text: Text,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ImageBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    pub image_url: Url,
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Input {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    pub label: Text,
+    pub element: Element,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Text>,
+    #[serde(default)]
+    pub optional: bool,
+    /// Sends a `block_actions` payload as soon as the element changes
+    #[serde(default)]
+    pub dispatch_action: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Section {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<Element>,
+}
+
+// ELEMENTS
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Element {
+    Button(Button),
+    ChannelsSelect(ChannelsSelect),
+    Checkboxes(Checkboxes),
+    ConversationsSelect(ConversationsSelect),
+    Datepicker(DatePicker),
+    ExternalSelect(ExternalSelect),
+    Image(ImageElement),
+    MultiConversationsSelect(MultiConversationsSelect),
+    MultiStaticSelect(MultiStaticSelect),
+    MultiUsersSelect(MultiUsersSelect),
+    PlainTextInput(PlainTextInput),
+    RadioButtons(RadioButtons),
+    StaticSelect(StaticSelect),
+    Timepicker(TimePicker),
+    UsersSelect(UsersSelect),
+    /// An element this crate doesn't know about, or that doesn't match its
+    /// type, kept as it was sent so it can be sent back unchanged
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Button {
+    pub action_id: String,
+    pub text: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ChannelsSelect {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channel: Option<conversation::Id>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Checkboxes {
+    pub action_id: String,
+    pub options: Vec<SelectOption>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub initial_options: Vec<SelectOption>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConversationsSelect {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversation: Option<conversation::Id>,
+    #[serde(default)]
+    pub default_to_current_conversation: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DatePicker {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// Formatted as `YYYY-MM-DD`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+}
+
+/// A select menu whose options are loaded from the app's options load URL
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ExternalSelect {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ImageElement {
+    pub image_url: Url,
+    pub alt_text: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MultiConversationsSelect {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub initial_conversations: Vec<conversation::Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MultiStaticSelect {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<SelectOption>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub option_groups: Vec<OptionGroup>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub initial_options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MultiUsersSelect {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub initial_users: Vec<user::Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PlainTextInput {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(default)]
+    pub multiline: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RadioButtons {
+    pub action_id: String,
+    pub options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StaticSelect {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<SelectOption>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub option_groups: Vec<OptionGroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct TimePicker {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// Formatted as `HH:mm`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_time: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UsersSelect {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_user: Option<user::Id>,
+}
+
+impl Text {
+    pub fn plain(text: String) -> Self {
+        Text::PlainText {
+            text,
+            emoji: Some(true),
+        }
+    }
+
+    pub fn mrkdwn(text: String) -> Self {
+        Text::Mrkdwn {
+            text,
+            verbatim: None,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            Text::PlainText { text, .. } | Text::Mrkdwn { text, .. } => text.as_str(),
+        }
+    }
+}
+
+impl From<Text> for ContextElement {
+    fn from(value: Text) -> Self {
+        match value {
+            Text::PlainText { text, emoji } => ContextElement::PlainText { text, emoji },
+            Text::Mrkdwn { text, verbatim } => ContextElement::Mrkdwn { text, verbatim },
+        }
+    }
+}
+
+impl From<ImageElement> for ContextElement {
+    fn from(value: ImageElement) -> Self {
+        ContextElement::Image {
+            image_url: value.image_url,
+            alt_text: value.alt_text,
+        }
+    }
+}
+
+impl SelectOption {
+    pub fn new(text: Text, value: String) -> Self {
+        Self {
+            text,
+            value,
+            description: None,
+            url: None,
+        }
+    }
+
+    pub fn set_description(self, description: Text) -> Self {
+        Self {
+            description: Some(description),
+            ..self
+        }
+    }
+}
+
+impl OptionGroup {
+    pub fn new(label: Text, options: Vec<SelectOption>) -> Self {
+        Self { label, options }
+    }
+}
+
+impl Block {
+    /// The block's `block_id`, if it has one
+    pub fn block_id(&self) -> Option<&str> {
+        let block_id = match self {
+            Block::Actions(block) => &block.block_id,
+            Block::Context(block) => &block.block_id,
+            Block::Divider(block) => &block.block_id,
+            Block::Header(block) => &block.block_id,
+            Block::Image(block) => &block.block_id,
+            Block::Input(block) => &block.block_id,
+            Block::Section(block) => &block.block_id,
+            Block::Unknown(value) => return value.get("block_id").and_then(Value::as_str),
+        };
+
+        block_id.as_deref()
+    }
+}
+
+impl Actions {
+    pub fn new(elements: Vec<Element>) -> Self {
+        Self {
+            block_id: None,
+            elements,
+        }
+    }
+
+    pub fn set_block_id(self, block_id: String) -> Self {
+        Self {
+            block_id: Some(block_id),
+            ..self
+        }
+    }
+}
+
+impl Context {
+    pub fn new(elements: Vec<ContextElement>) -> Self {
+        Self {
+            block_id: None,
+            elements,
+        }
+    }
+
+    pub fn set_block_id(self, block_id: String) -> Self {
+        Self {
+            block_id: Some(block_id),
+            ..self
+        }
+    }
+}
+
+impl Divider {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Header {
+    pub fn new(text: String) -> Self {
+        Self {
+            block_id: None,
+            text: Text::plain(text),
+        }
+    }
+
+    pub fn set_block_id(self, block_id: String) -> Self {
+        Self {
+            block_id: Some(block_id),
+            ..self
+        }
+    }
+}
+
+impl ImageBlock {
+    pub fn new(image_url: Url, alt_text: String) -> Self {
+        Self {
+            block_id: None,
+            image_url,
+            alt_text,
+            title: None,
+        }
+    }
+
+    pub fn set_block_id(self, block_id: String) -> Self {
+        Self {
+            block_id: Some(block_id),
+            ..self
+        }
+    }
+
+    pub fn set_title(self, title: String) -> Self {
+        Self {
+            title: Some(Text::plain(title)),
+            ..self
+        }
+    }
+}
+
+impl Input {
+    /// `block_id` is what the element's value is keyed by in a view's state
+    pub fn new(block_id: String, label: String, element: Element) -> Self {
+        Self {
+            block_id: Some(block_id),
+            label: Text::plain(label),
+            element,
+            hint: None,
+            optional: false,
+            dispatch_action: false,
+        }
+    }
+
+    pub fn set_hint(self, hint: String) -> Self {
+        Self {
+            hint: Some(Text::plain(hint)),
+            ..self
+        }
+    }
+
+    pub fn set_optional(self, optional: bool) -> Self {
+        Self { optional, ..self }
+    }
+
+    pub fn set_dispatch_action(self, dispatch_action: bool) -> Self {
+        Self {
+            dispatch_action,
+            ..self
+        }
+    }
+}
+
+impl Section {
+    pub fn new(text: Text) -> Self {
+        Self {
+            block_id: None,
+            text: Some(text),
+            fields: Vec::new(),
+            accessory: None,
+        }
+    }
+
+    /// A section made of up to 10 fields shown in two columns
+    pub fn from_fields(fields: Vec<Text>) -> Self {
+        Self {
+            block_id: None,
+            text: None,
+            fields,
+            accessory: None,
+        }
+    }
+
+    pub fn set_block_id(self, block_id: String) -> Self {
+        Self {
+            block_id: Some(block_id),
+            ..self
+        }
+    }
+
+    pub fn set_accessory(self, accessory: Element) -> Self {
+        Self {
+            accessory: Some(accessory),
+            ..self
+        }
+    }
+}
+
+impl Button {
+    pub fn new(action_id: String, text: String) -> Self {
+        Self {
+            action_id,
+            text: Text::plain(text),
+            value: None,
+            url: None,
+            style: None,
+        }
+    }
+
+    pub fn set_value(self, value: String) -> Self {
+        Self {
+            value: Some(value),
+            ..self
+        }
+    }
+
+    pub fn set_url(self, url: Url) -> Self {
+        Self {
+            url: Some(url),
+            ..self
+        }
+    }
+
+    pub fn set_style(self, style: ButtonStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+}
+
+impl ChannelsSelect {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            initial_channel: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_channel(self, channel: conversation::Id) -> Self {
+        Self {
+            initial_channel: Some(channel),
+            ..self
+        }
+    }
+}
+
+impl Checkboxes {
+    pub fn new(action_id: String, options: Vec<SelectOption>) -> Self {
+        Self {
+            action_id,
+            options,
+            initial_options: Vec::new(),
+        }
+    }
+
+    pub fn set_initial_options(self, initial_options: Vec<SelectOption>) -> Self {
+        Self {
+            initial_options,
+            ..self
+        }
+    }
+}
+
+impl ConversationsSelect {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            initial_conversation: None,
+            default_to_current_conversation: false,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_conversation(self, conversation: conversation::Id) -> Self {
+        Self {
+            initial_conversation: Some(conversation),
+            ..self
+        }
+    }
+}
+
+impl DatePicker {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            initial_date: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_date(self, date: time::Date) -> Self {
+        let initial_date = format!(
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            u8::from(date.month()),
+            date.day()
+        );
+
+        Self {
+            initial_date: Some(initial_date),
+            ..self
+        }
+    }
+}
+
+impl ExternalSelect {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            min_query_length: None,
+            initial_option: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_min_query_length(self, min_query_length: u32) -> Self {
+        Self {
+            min_query_length: Some(min_query_length),
+            ..self
+        }
+    }
+
+    pub fn set_initial_option(self, option: SelectOption) -> Self {
+        Self {
+            initial_option: Some(option),
+            ..self
+        }
+    }
+}
+
+impl ImageElement {
+    pub fn new(image_url: Url, alt_text: String) -> Self {
+        Self {
+            image_url,
+            alt_text,
+        }
+    }
+}
+
+impl MultiConversationsSelect {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            initial_conversations: Vec::new(),
+            max_selected_items: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_conversations(self, conversations: Vec<conversation::Id>) -> Self {
+        Self {
+            initial_conversations: conversations,
+            ..self
+        }
+    }
+
+    pub fn set_max_selected_items(self, max_selected_items: u32) -> Self {
+        Self {
+            max_selected_items: Some(max_selected_items),
+            ..self
+        }
+    }
+}
+
+impl MultiStaticSelect {
+    pub fn new(action_id: String, options: Vec<SelectOption>) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            options,
+            option_groups: Vec::new(),
+            initial_options: Vec::new(),
+            max_selected_items: None,
+        }
+    }
+
+    pub fn from_groups(action_id: String, option_groups: Vec<OptionGroup>) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            options: Vec::new(),
+            option_groups,
+            initial_options: Vec::new(),
+            max_selected_items: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_options(self, initial_options: Vec<SelectOption>) -> Self {
+        Self {
+            initial_options,
+            ..self
+        }
+    }
+
+    pub fn set_max_selected_items(self, max_selected_items: u32) -> Self {
+        Self {
+            max_selected_items: Some(max_selected_items),
+            ..self
+        }
+    }
+}
+
+impl MultiUsersSelect {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            initial_users: Vec::new(),
+            max_selected_items: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_users(self, users: Vec<user::Id>) -> Self {
+        Self {
+            initial_users: users,
+            ..self
+        }
+    }
+
+    pub fn set_max_selected_items(self, max_selected_items: u32) -> Self {
+        Self {
+            max_selected_items: Some(max_selected_items),
+            ..self
+        }
+    }
+}
+
+impl PlainTextInput {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            initial_value: None,
+            multiline: false,
+            min_length: None,
+            max_length: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_value(self, initial_value: String) -> Self {
+        Self {
+            initial_value: Some(initial_value),
+            ..self
+        }
+    }
+
+    pub fn set_multiline(self, multiline: bool) -> Self {
+        Self { multiline, ..self }
+    }
+
+    pub fn set_length(self, min_length: Option<u32>, max_length: Option<u32>) -> Self {
+        Self {
+            min_length,
+            max_length,
+            ..self
+        }
+    }
+}
+
+impl RadioButtons {
+    pub fn new(action_id: String, options: Vec<SelectOption>) -> Self {
+        Self {
+            action_id,
+            options,
+            initial_option: None,
+        }
+    }
+
+    pub fn set_initial_option(self, option: SelectOption) -> Self {
+        Self {
+            initial_option: Some(option),
+            ..self
+        }
+    }
+}
+
+impl StaticSelect {
+    pub fn new(action_id: String, options: Vec<SelectOption>) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            options,
+            option_groups: Vec::new(),
+            initial_option: None,
+        }
+    }
+
+    pub fn from_groups(action_id: String, option_groups: Vec<OptionGroup>) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            options: Vec::new(),
+            option_groups,
+            initial_option: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_option(self, option: SelectOption) -> Self {
+        Self {
+            initial_option: Some(option),
+            ..self
+        }
+    }
+}
+
+impl TimePicker {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            initial_time: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_time(self, time: time::Time) -> Self {
+        let initial_time = format!("{:02}:{:02}", time.hour(), time.minute());
+
+        Self {
+            initial_time: Some(initial_time),
+            ..self
+        }
+    }
+}
+
+impl UsersSelect {
+    pub fn new(action_id: String) -> Self {
+        Self {
+            action_id,
+            placeholder: None,
+            initial_user: None,
+        }
+    }
+
+    pub fn set_placeholder(self, placeholder: String) -> Self {
+        Self {
+            placeholder: Some(Text::plain(placeholder)),
+            ..self
+        }
+    }
+
+    pub fn set_initial_user(self, user: user::Id) -> Self {
+        Self {
+            initial_user: Some(user),
+            ..self
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($enum:ident, $($variant:ident($ty:ty)),+ $(,)?) => {
+        $(
+            impl From<$ty> for $enum {
+                fn from(value: $ty) -> Self {
+                    $enum::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(
+    Block,
+    Actions(Actions),
+    Context(Context),
+    Divider(Divider),
+    Header(Header),
+    Image(ImageBlock),
+    Input(Input),
+    Section(Section),
+);
+
+impl_from!(
+    Element,
+    Button(Button),
+    ChannelsSelect(ChannelsSelect),
+    Checkboxes(Checkboxes),
+    ConversationsSelect(ConversationsSelect),
+    Datepicker(DatePicker),
+    ExternalSelect(ExternalSelect),
+    Image(ImageElement),
+    MultiConversationsSelect(MultiConversationsSelect),
+    MultiStaticSelect(MultiStaticSelect),
+    MultiUsersSelect(MultiUsersSelect),
+    PlainTextInput(PlainTextInput),
+    RadioButtons(RadioButtons),
+    StaticSelect(StaticSelect),
+    Timepicker(TimePicker),
+    UsersSelect(UsersSelect),
+);
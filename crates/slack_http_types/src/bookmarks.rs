@@ -0,0 +1,251 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::{conversation, offset_date_time_from_unix_ts, team, user};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Id(pub String);
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Link,
+    Message,
+    File,
+    Folder,
+    /// A kind this crate doesn't know about. Only read from Slack, never sent.
+    #[serde(other)]
+    #[serde(skip_serializing)]
+    Unknown,
+}
+
+/// The kind of a bookmark to add. Unlike `Kind` it has no `Unknown`, so only
+/// kinds Slack accepts can be sent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NewKind {
+    Link,
+    Message,
+    File,
+    Folder,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Bookmark {
+    pub id: Id,
+    pub channel_id: conversation::Id,
+    pub title: String,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_url")]
+    pub link: Option<Url>,
+    pub emoji: Option<String>,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_url")]
+    pub icon_url: Option<Url>,
+    #[serde(rename = "type")]
+    pub kind: Kind,
+    pub entity_id: Option<String>,
+    pub parent_id: Option<Id>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_created: OffsetDateTime,
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::optional_offset_date_time_from_unix_ts")]
+    pub date_updated: Option<OffsetDateTime>,
+    pub rank: Option<String>,
+    pub last_updated_by_user_id: Option<user::Id>,
+    pub last_updated_by_team_id: Option<team::Id>,
+    pub shortcut_id: Option<String>,
+    pub app_id: Option<String>,
+}
+
+/// A bookmark to add to a channel
+pub struct NewBookmark {
+    pub title: String,
+    pub kind: NewKind,
+    pub link: Option<Url>,
+    pub emoji: Option<String>,
+    pub entity_id: Option<String>,
+    pub parent_id: Option<Id>,
+}
+
+/// Changes to an existing bookmark. Anything left as `None` is kept as is.
+#[derive(Default)]
+pub struct BookmarkEdit {
+    pub title: Option<String>,
+    pub link: Option<Url>,
+    pub emoji: Option<String>,
+}
+
+// ADD
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AddResponse {
+    Ok { bookmark: Box<Bookmark> },
+    Error { error: String },
+}
+
+// EDIT
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum EditResponse {
+    Ok { bookmark: Box<Bookmark> },
+    Error { error: String },
+}
+
+// REMOVE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoveResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// LIST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ListResponse {
+    Ok { bookmarks: Vec<Bookmark> },
+    Error { error: String },
+}
+
+impl Id {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl NewKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            NewKind::Link => "link",
+            NewKind::Message => "message",
+            NewKind::File => "file",
+            NewKind::Folder => "folder",
+        }
+    }
+}
+
+impl NewBookmark {
+    pub fn link(title: String, link: Url) -> Self {
+        Self {
+            title,
+            kind: NewKind::Link,
+            link: Some(link),
+            emoji: None,
+            entity_id: None,
+            parent_id: None,
+        }
+    }
+
+    /// Bookmarks a message or file by its ID
+    pub fn entity(title: String, kind: NewKind, entity_id: String) -> Self {
+        Self {
+            title,
+            kind,
+            link: None,
+            emoji: None,
+            entity_id: Some(entity_id),
+            parent_id: None,
+        }
+    }
+
+    pub fn set_emoji(self, emoji: String) -> Self {
+        let emoji = format!(":{emoji}:");
+
+        Self {
+            emoji: Some(emoji),
+            ..self
+        }
+    }
+
+    pub fn set_parent_id(self, parent_id: Id) -> Self {
+        Self {
+            parent_id: Some(parent_id),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, &str)> {
+        let mut opts = vec![("title", self.title.as_str()), ("type", self.kind.as_str())];
+
+        if let Some(link) = &self.link {
+            opts.push(("link", link.as_str()))
+        }
+
+        if let Some(emoji) = &self.emoji {
+            opts.push(("emoji", emoji.as_str()))
+        }
+
+        if let Some(entity_id) = &self.entity_id {
+            opts.push(("entity_id", entity_id.as_str()))
+        }
+
+        if let Some(parent_id) = &self.parent_id {
+            opts.push(("parent_id", parent_id.as_str()))
+        }
+
+        opts
+    }
+}
+
+impl BookmarkEdit {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_title(self, title: String) -> Self {
+        Self {
+            title: Some(title),
+            ..self
+        }
+    }
+
+    pub fn set_link(self, link: Url) -> Self {
+        Self {
+            link: Some(link),
+            ..self
+        }
+    }
+
+    pub fn set_emoji(self, emoji: String) -> Self {
+        let emoji = format!(":{emoji}:");
+
+        Self {
+            emoji: Some(emoji),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, &str)> {
+        let mut opts = Vec::new();
+
+        if let Some(title) = &self.title {
+            opts.push(("title", title.as_str()))
+        }
+
+        if let Some(link) = &self.link {
+            opts.push(("link", link.as_str()))
+        }
+
+        if let Some(emoji) = &self.emoji {
+            opts.push(("emoji", emoji.as_str()))
+        }
+
+        opts
+    }
+}
+
+/// Slack sends an empty string instead of `null` for bookmarks without a link
+/// or icon.
+fn optional_url<'de, D>(deserializer: D) -> Result<Option<Url>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(url) if !url.is_empty() => {
+            Url::parse(&url).map(Some).map_err(serde::de::Error::custom)
+        }
+        _ => Ok(None),
+    }
+}
@@ -1,24 +1,65 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use url::Url;
 
-use crate::user;
+use crate::{blocks::Block, user};
 
 // TODO: Add the following options:
 // 1. thread_ts
 // 2. reply_broadcast
 // 3. parse
 // 4. metadata
+#[derive(Clone, Debug, Serialize)]
 pub struct MessageOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_url: Option<Url>,
     pub link_names: bool,
+    #[serde(rename = "mrkdwn")]
     pub markdown: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unfurl_links: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unfurl_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+/// A legacy secondary attachment, shown below the message with a colored bar
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Attachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    /// A hex color like `#36a64f`, or `good`, `warning` or `danger`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_link: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<AttachmentField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AttachmentField {
+    pub title: String,
+    pub value: String,
+    /// Whether it's short enough to be shown side by side with other fields
+    pub short: bool,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum MessageResponse {
@@ -115,3 +156,84 @@ impl Default for MessageOptions {
         }
     }
 }
+
+impl Attachment {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Shown in notifications and clients that can't show attachments
+    pub fn set_fallback(self, fallback: String) -> Self {
+        Self {
+            fallback: Some(fallback),
+            ..self
+        }
+    }
+
+    pub fn set_color(self, color: String) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    pub fn set_pretext(self, pretext: String) -> Self {
+        Self {
+            pretext: Some(pretext),
+            ..self
+        }
+    }
+
+    pub fn set_title(self, title: String) -> Self {
+        Self {
+            title: Some(title),
+            ..self
+        }
+    }
+
+    pub fn set_title_link(self, title_link: Url) -> Self {
+        Self {
+            title_link: Some(title_link),
+            ..self
+        }
+    }
+
+    pub fn set_text(self, text: String) -> Self {
+        Self {
+            text: Some(text),
+            ..self
+        }
+    }
+
+    pub fn set_fields(self, fields: Vec<AttachmentField>) -> Self {
+        Self { fields, ..self }
+    }
+
+    pub fn set_image_url(self, image_url: Url) -> Self {
+        Self {
+            image_url: Some(image_url),
+            ..self
+        }
+    }
+
+    pub fn set_footer(self, footer: String) -> Self {
+        Self {
+            footer: Some(footer),
+            ..self
+        }
+    }
+
+    pub fn set_blocks(self, blocks: Vec<Block>) -> Self {
+        Self { blocks, ..self }
+    }
+}
+
+impl AttachmentField {
+    pub fn new(title: String, value: String, short: bool) -> Self {
+        Self {
+            title,
+            value,
+            short,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{blocks::Block, conversation, team, user, views::TriggerId};
+
+/// What Slack sends to a slash command's request URL, as a form
+#[derive(Debug, Deserialize)]
+pub struct SlashCommand {
+    /// The command itself, e.g. `/deploy`
+    pub command: String,
+    /// Everything the user typed after the command. See `SlashCommand::args`.
+    #[serde(default)]
+    pub text: String,
+    pub user_id: user::Id,
+    #[serde(default)]
+    pub user_name: String,
+    pub channel_id: conversation::Id,
+    #[serde(default)]
+    pub channel_name: String,
+    pub team_id: team::Id,
+    #[serde(default)]
+    pub team_domain: String,
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
+    /// Where to send up to 5 replies in the next 30 minutes
+    pub response_url: Url,
+    pub trigger_id: TriggerId,
+    pub api_app_id: String,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+/// An argument in a slash command's text
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Arg {
+    /// A user mentioned as `<@U123|name>`
+    User { id: user::Id, name: Option<String> },
+    /// A channel mentioned as `<#C123|name>`
+    Conversation {
+        id: conversation::Id,
+        name: Option<String>,
+    },
+    /// Anything else, with surrounding double quotes removed
+    Text(String),
+}
+
+/// The reply to a slash command, either as the body of the response to
+/// Slack's request or sent to the `response_url`
+#[derive(Clone, Debug, Serialize)]
+pub struct CommandResponse {
+    pub response_type: ResponseType,
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+    /// Visible to everyone in the channel, along with the command
+    InChannel,
+    /// Only visible to the user who ran the command
+    Ephemeral,
+}
+
+impl SlashCommand {
+    pub fn from_form(body: &[u8]) -> Result<Self, serde_urlencoded::de::Error> {
+        serde_urlencoded::from_bytes(body)
+    }
+
+    /// Splits the text into arguments. Mentions are only escaped when the
+    /// command has "Escape channels, users, and links" turned on.
+    pub fn args(&self) -> Vec<Arg> {
+        parse_args(&self.text)
+    }
+}
+
+/// Splits slash command text on whitespace, keeping double quoted text
+/// together, and resolves user and channel mentions
+pub fn parse_args(text: &str) -> Vec<Arg> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut in_token = false;
+
+    for c in text.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                in_token = true;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if in_token {
+                    args.push(Arg::from_token(std::mem::take(&mut current)));
+                    in_token = false;
+                }
+            }
+            c => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+
+    if in_token {
+        args.push(Arg::from_token(current));
+    }
+
+    args
+}
+
+impl Arg {
+    fn from_token(token: String) -> Self {
+        let Some(escaped) = token.strip_prefix('<').and_then(|t| t.strip_suffix('>')) else {
+            return Arg::Text(token);
+        };
+
+        let (id, name) = match escaped.split_once('|') {
+            Some((id, name)) => (id, Some(name.to_string())),
+            None => (escaped, None),
+        };
+
+        if let Some(id) = id.strip_prefix('@') {
+            Arg::User {
+                id: user::Id(id.to_string()),
+                name,
+            }
+        } else if let Some(id) = id.strip_prefix('#') {
+            Arg::Conversation {
+                id: conversation::Id(id.to_string()),
+                name,
+            }
+        } else {
+            Arg::Text(token)
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Arg::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_user(&self) -> Option<&user::Id> {
+        match self {
+            Arg::User { id, .. } => Some(id),
+            _ => None,
+        }
+    }
+
+    pub fn as_conversation(&self) -> Option<&conversation::Id> {
+        match self {
+            Arg::Conversation { id, .. } => Some(id),
+            _ => None,
+        }
+    }
+}
+
+impl CommandResponse {
+    pub fn in_channel(text: String) -> Self {
+        Self {
+            response_type: ResponseType::InChannel,
+            text,
+            blocks: Vec::new(),
+        }
+    }
+
+    pub fn ephemeral(text: String) -> Self {
+        Self {
+            response_type: ResponseType::Ephemeral,
+            text,
+            blocks: Vec::new(),
+        }
+    }
+
+    /// When there are blocks, the text is only used for notifications
+    pub fn set_blocks(self, blocks: Vec<Block>) -> Self {
+        Self { blocks, ..self }
+    }
+}
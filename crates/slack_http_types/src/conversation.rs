@@ -1,16 +1,22 @@
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
+use url::Url;
 
 use crate::{
     offset_date_time_from_unix_ts,
     page::{self, Limit},
-    user,
+    team, user,
 };
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(transparent)]
 pub struct Id(pub String);
 
+/// ID of a Slack Connect invite
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct InviteId(pub String);
+
 #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Conversation {
     pub id: Id,
@@ -58,6 +64,12 @@ impl Id {
     }
 }
 
+impl InviteId {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
 impl Default for ListOptions {
     fn default() -> Self {
         Self {
@@ -172,3 +184,204 @@ pub enum MembersResponse {
         error: String,
     },
 }
+
+// INVITE SHARED
+/// Who to invite to a Slack Connect channel. Slack only accepts one invitee per
+/// request.
+pub enum SharedInvitee {
+    Email(String),
+    User(user::Id),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SharedInvite {
+    pub invite_id: InviteId,
+    pub conf_code: Option<String>,
+    pub url: Option<Url>,
+    pub is_legacy_shared_channel: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum InviteSharedResponse {
+    Ok(SharedInvite),
+    Error { error: String },
+}
+
+// ACCEPT SHARED INVITE
+#[derive(Clone, Default)]
+pub struct AcceptSharedInviteOptions {
+    pub is_private: bool,
+    pub free_trial_accepted: bool,
+    pub team_id: Option<team::Id>,
+}
+
+impl AcceptSharedInviteOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_is_private(self, is_private: bool) -> Self {
+        Self { is_private, ..self }
+    }
+
+    pub fn set_free_trial_accepted(self, free_trial_accepted: bool) -> Self {
+        Self {
+            free_trial_accepted,
+            ..self
+        }
+    }
+
+    pub fn set_team_id(self, team_id: team::Id) -> Self {
+        Self {
+            team_id: Some(team_id),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, &str)> {
+        let mut opts = vec![
+            ("is_private", if self.is_private { "true" } else { "false" }),
+            (
+                "free_trial_accepted",
+                if self.free_trial_accepted {
+                    "true"
+                } else {
+                    "false"
+                },
+            ),
+        ];
+
+        if let Some(team_id) = &self.team_id {
+            opts.push(("team_id", team_id.0.as_str()))
+        }
+
+        opts
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AcceptedSharedInvite {
+    pub channel_id: Id,
+    pub invite_id: InviteId,
+    pub implicit_approval: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AcceptSharedInviteResponse {
+    Ok(AcceptedSharedInvite),
+    Error { error: String },
+}
+
+// APPROVE/DECLINE SHARED INVITE
+// `Error` comes first since `Ok` would also match an error response. The same
+// goes for every other response that's only `ok` on success.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ApproveSharedInviteResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DeclineSharedInviteResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// LIST CONNECT INVITES
+#[derive(Debug, Deserialize)]
+pub struct ConnectInvite {
+    pub direction: InviteDirection,
+    pub status: String,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_last_updated: OffsetDateTime,
+    pub invite_type: String,
+    pub invite: InviteDetails,
+    pub channel: SharedChannel,
+    #[serde(default)]
+    pub acceptances: Vec<Acceptance>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteDirection {
+    Incoming,
+    Outgoing,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InviteDetails {
+    pub id: InviteId,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_created: OffsetDateTime,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_invalid: OffsetDateTime,
+    pub inviting_team: ConnectTeam,
+    pub inviting_user: Option<ConnectUser>,
+    pub recipient_email: Option<String>,
+    pub recipient_user_id: Option<user::Id>,
+    pub link: Option<Url>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConnectTeam {
+    pub id: team::Id,
+    pub name: String,
+    pub domain: Option<String>,
+    pub is_verified: Option<bool>,
+    pub requires_sponsorship: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConnectUser {
+    pub id: user::Id,
+    pub team_id: team::Id,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SharedChannel {
+    pub id: Id,
+    pub name: String,
+    pub is_private: bool,
+    pub is_im: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Acceptance {
+    pub approval_status: String,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_accepted: OffsetDateTime,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_invalid: OffsetDateTime,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_last_updated: OffsetDateTime,
+    pub accepting_team: ConnectTeam,
+    pub accepting_user: Option<ConnectUser>,
+    #[serde(default)]
+    pub reviews: Vec<Review>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Review {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_review: OffsetDateTime,
+    pub reviewing_team: ConnectTeam,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ListConnectInvitesResponse {
+    Ok {
+        invites: Vec<ConnectInvite>,
+        response_metadata: page::ResponseMetadata,
+    },
+    Error {
+        error: String,
+    },
+}
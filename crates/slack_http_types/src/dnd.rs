@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::{offset_date_time_from_unix_ts, optional_offset_date_time_from_unix_ts, user};
+
+/// A user's Do Not Disturb settings. The snooze fields are only sent when
+/// asking about the authed user.
+#[derive(Debug, Deserialize)]
+pub struct DndStatus {
+    pub dnd_enabled: bool,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts")]
+    pub next_dnd_start_ts: Option<OffsetDateTime>,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts")]
+    pub next_dnd_end_ts: Option<OffsetDateTime>,
+    pub snooze_enabled: Option<bool>,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts")]
+    pub snooze_endtime: Option<OffsetDateTime>,
+    /// Seconds left until the snooze ends
+    pub snooze_remaining: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Snooze {
+    pub snooze_enabled: bool,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub snooze_endtime: OffsetDateTime,
+    /// Seconds left until the snooze ends
+    pub snooze_remaining: u64,
+}
+
+impl DndStatus {
+    /// Checks if notifications are paused at `now`, either by the user's DND
+    /// schedule or by a snooze.
+    pub fn is_active_at(&self, now: OffsetDateTime) -> bool {
+        let snoozed = match (self.snooze_enabled, self.snooze_endtime) {
+            (Some(true), Some(end)) => now < end,
+            (Some(true), None) => true,
+            _ => false,
+        };
+
+        let scheduled = match (self.next_dnd_start_ts, self.next_dnd_end_ts) {
+            (Some(start), Some(end)) => self.dnd_enabled && start <= now && now < end,
+            _ => false,
+        };
+
+        snoozed || scheduled
+    }
+}
+
+// INFO
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum InfoResponse {
+    Ok(DndStatus),
+    Error { error: String },
+}
+
+// TEAM INFO
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum TeamInfoResponse {
+    Ok { users: HashMap<user::Id, DndStatus> },
+    Error { error: String },
+}
+
+// SET SNOOZE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SetSnoozeResponse {
+    Ok(Snooze),
+    Error { error: String },
+}
+
+// END SNOOZE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum EndSnoozeResponse {
+    Ok(DndStatus),
+    Error { error: String },
+}
+
+// END DND
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum EndDndResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
@@ -10,6 +10,12 @@ pub enum Error {
     Deserialize(reqwest::Error),
     #[error("failed to parse URL. reason: {0}")]
     Url(#[from] url::ParseError),
+    #[error("failed to serialize request to slack. reason: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("failed to write file from slack. reason: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("refusing to send the token to a URL outside of slack: {0}")]
+    NotSlackUrl(url::Url),
 }
 
 impl Error {
@@ -19,4 +25,10 @@ impl Error {
             _ => None,
         }
     }
+
+    /// Whether a view was changed since the `hash` that was sent along with
+    /// an update was read
+    pub fn is_hash_conflict(&self) -> bool {
+        self.get_slack_error() == Some("hash_conflict")
+    }
 }
@@ -0,0 +1,390 @@
+use serde::{de, Deserialize, Deserializer};
+use serde_json::{from_value, Value};
+use time::OffsetDateTime;
+
+use crate::{
+    blocks::Block, conversation, files, offset_date_time_from_unix_ts,
+    offset_date_time_from_unix_ts_with_nano, optional_offset_date_time_from_unix_ts_with_nano,
+    reactions, team, user, views::View,
+};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[serde(transparent)]
+pub struct EventId(pub String);
+
+/// The body of a request from the Events API
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Envelope {
+    UrlVerification(UrlVerification),
+    EventCallback(Box<EventCallback>),
+    AppRateLimited(AppRateLimited),
+}
+
+/// Sent when the request URL is set. Has to be answered with `challenge`.
+#[derive(Debug, Deserialize)]
+pub struct UrlVerification {
+    pub challenge: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventCallback {
+    pub team_id: team::Id,
+    pub api_app_id: String,
+    pub event: Event,
+    pub event_id: EventId,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub event_time: OffsetDateTime,
+    /// Installations the event is visible to. Slack only sends one, see
+    /// `apps.event.authorizations.list` for the rest.
+    #[serde(default)]
+    pub authorizations: Vec<Authorization>,
+    #[serde(default)]
+    pub is_ext_shared_channel: bool,
+    pub event_context: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Authorization {
+    pub enterprise_id: Option<String>,
+    pub team_id: Option<team::Id>,
+    pub user_id: user::Id,
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+/// Sent when the app got more than 30,000 events from a team in an hour.
+/// Events are dropped until the minute after `minute_rate_limited`.
+#[derive(Debug, Deserialize)]
+pub struct AppRateLimited {
+    pub team_id: team::Id,
+    pub api_app_id: String,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub minute_rate_limited: OffsetDateTime,
+}
+
+/// The event inside an `event_callback`. Events this crate doesn't have a
+/// type for, or that don't match their type anymore, end up in `Unknown` as
+/// they were sent.
+#[derive(Debug)]
+pub enum Event {
+    AppHomeOpened(AppHomeOpened),
+    AppMention(AppMention),
+    ChannelCreated(ChannelCreated),
+    MemberJoinedChannel(MemberJoinedChannel),
+    MemberLeftChannel(MemberLeftChannel),
+    Message(MessageEvent),
+    ReactionAdded(ReactionAdded),
+    ReactionRemoved(ReactionRemoved),
+    TeamJoin(TeamJoin),
+    UserChange(UserChange),
+    Unknown(Value),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AppHomeOpened {
+    pub user: user::Id,
+    pub channel: conversation::Id,
+    /// `home` or `messages`
+    pub tab: String,
+    /// The home tab as it was last published. Missing until one has been.
+    pub view: Option<Box<View>>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AppMention {
+    pub user: user::Id,
+    pub channel: conversation::Id,
+    pub text: String,
+    #[serde(rename = "ts")]
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub timestamp: OffsetDateTime,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts_with_nano")]
+    pub thread_ts: Option<OffsetDateTime>,
+    pub team: Option<team::Id>,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChannelCreated {
+    pub channel: CreatedChannel,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreatedChannel {
+    pub id: conversation::Id,
+    pub name: String,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub created: OffsetDateTime,
+    pub creator: user::Id,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MemberJoinedChannel {
+    pub user: user::Id,
+    pub channel: conversation::Id,
+    /// `C` for public channels and `G` for private ones
+    pub channel_type: String,
+    pub team: Option<team::Id>,
+    /// Missing when the user joined by themselves
+    pub inviter: Option<user::Id>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MemberLeftChannel {
+    pub user: user::Id,
+    pub channel: conversation::Id,
+    pub channel_type: String,
+    pub team: Option<team::Id>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+/// A `message` event. Edits and deletions have a shape of their own, every
+/// other subtype is a `Message` with `subtype` set.
+#[derive(Debug)]
+pub enum MessageEvent {
+    Message(Box<Message>),
+    Changed(Box<MessageChanged>),
+    Deleted(Box<MessageDeleted>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    pub channel: conversation::Id,
+    /// `channel`, `group`, `im` or `mpim`
+    pub channel_type: Option<String>,
+    pub subtype: Option<MessageSubtype>,
+    pub user: Option<user::Id>,
+    pub bot_id: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    #[serde(rename = "ts")]
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub timestamp: OffsetDateTime,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts_with_nano")]
+    pub thread_ts: Option<OffsetDateTime>,
+    pub team: Option<team::Id>,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageSubtype {
+    BotMessage,
+    ChannelArchive,
+    ChannelJoin,
+    ChannelLeave,
+    ChannelName,
+    ChannelPurpose,
+    ChannelTopic,
+    ChannelUnarchive,
+    FileShare,
+    MeMessage,
+    ThreadBroadcast,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MessageChanged {
+    pub channel: conversation::Id,
+    pub channel_type: Option<String>,
+    pub message: ChangedMessage,
+    pub previous_message: Option<ChangedMessage>,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MessageDeleted {
+    pub channel: conversation::Id,
+    pub channel_type: Option<String>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub deleted_ts: OffsetDateTime,
+    pub previous_message: Option<ChangedMessage>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+/// A message before or after it was edited or deleted
+#[derive(Debug, Deserialize)]
+pub struct ChangedMessage {
+    pub subtype: Option<MessageSubtype>,
+    pub user: Option<user::Id>,
+    pub bot_id: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    #[serde(rename = "ts")]
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub timestamp: OffsetDateTime,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts_with_nano")]
+    pub thread_ts: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    pub edited: Option<Edited>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Edited {
+    pub user: user::Id,
+    #[serde(rename = "ts")]
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub timestamp: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReactionAdded {
+    pub user: user::Id,
+    pub reaction: reactions::Name,
+    /// Author of the item that was reacted to
+    pub item_user: Option<user::Id>,
+    pub item: ReactionItem,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReactionRemoved {
+    pub user: user::Id,
+    pub reaction: reactions::Name,
+    pub item_user: Option<user::Id>,
+    pub item: ReactionItem,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub event_ts: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReactionItem {
+    Message {
+        channel: conversation::Id,
+        #[serde(rename = "ts")]
+        #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+        timestamp: OffsetDateTime,
+    },
+    File {
+        file: files::Id,
+    },
+    FileComment {
+        file: files::Id,
+        file_comment: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TeamJoin {
+    pub user: Box<user::User>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserChange {
+    pub user: Box<user::User>,
+}
+
+impl EventId {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Event {
+    /// The event's `type`, e.g. `app_mention`
+    pub fn kind(&self) -> &str {
+        match self {
+            Event::AppHomeOpened(_) => "app_home_opened",
+            Event::AppMention(_) => "app_mention",
+            Event::ChannelCreated(_) => "channel_created",
+            Event::MemberJoinedChannel(_) => "member_joined_channel",
+            Event::MemberLeftChannel(_) => "member_left_channel",
+            Event::Message(_) => "message",
+            Event::ReactionAdded(_) => "reaction_added",
+            Event::ReactionRemoved(_) => "reaction_removed",
+            Event::TeamJoin(_) => "team_join",
+            Event::UserChange(_) => "user_change",
+            Event::Unknown(value) => value
+                .get("type")
+                .and_then(Value::as_str)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        let kind = value
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| de::Error::missing_field("type"))?
+            .to_string();
+
+        let event = match kind.as_str() {
+            "app_home_opened" => AppHomeOpened::deserialize(&value).map(Event::AppHomeOpened),
+            "app_mention" => AppMention::deserialize(&value).map(Event::AppMention),
+            "channel_created" => ChannelCreated::deserialize(&value).map(Event::ChannelCreated),
+            "member_joined_channel" => {
+                MemberJoinedChannel::deserialize(&value).map(Event::MemberJoinedChannel)
+            }
+            "member_left_channel" => {
+                MemberLeftChannel::deserialize(&value).map(Event::MemberLeftChannel)
+            }
+            "message" => MessageEvent::deserialize(&value).map(Event::Message),
+            "reaction_added" => ReactionAdded::deserialize(&value).map(Event::ReactionAdded),
+            "reaction_removed" => ReactionRemoved::deserialize(&value).map(Event::ReactionRemoved),
+            "team_join" => TeamJoin::deserialize(&value).map(Event::TeamJoin),
+            "user_change" => UserChange::deserialize(&value).map(Event::UserChange),
+            _ => return Ok(Event::Unknown(value)),
+        };
+
+        // Slack changes events without notice. One that doesn't fit its type
+        // anymore shouldn't fail the whole envelope.
+        Ok(event.unwrap_or(Event::Unknown(value)))
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        let subtype = value
+            .get("subtype")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let message = match subtype.as_deref() {
+            Some("message_changed") => {
+                from_value(value).map(|changed| MessageEvent::Changed(Box::new(changed)))
+            }
+            Some("message_deleted") => {
+                from_value(value).map(|deleted| MessageEvent::Deleted(Box::new(deleted)))
+            }
+            _ => from_value(value).map(|message| MessageEvent::Message(Box::new(message))),
+        };
+
+        message.map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,579 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::{
+    conversation, offset_date_time_from_unix_ts,
+    page::{Limit, Paging, ResponseMetadata},
+    user,
+};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Id(pub String);
+
+/// ID a remote file is known by in the system that hosts it
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct ExternalId(pub String);
+
+#[derive(Debug, Deserialize)]
+pub struct File {
+    pub id: Id,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub created: OffsetDateTime,
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub mimetype: Option<String>,
+    pub filetype: Option<String>,
+    pub pretty_type: Option<String>,
+    pub user: Option<user::Id>,
+    /// Size in bytes
+    #[serde(default)]
+    pub size: u64,
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub is_external: bool,
+    pub external_type: Option<String>,
+    pub external_id: Option<ExternalId>,
+    pub external_url: Option<Url>,
+    #[serde(default)]
+    pub is_public: bool,
+    #[serde(default)]
+    pub public_url_shared: bool,
+    /// Needs the bearer token to fetch. See `files::download`.
+    pub url_private: Option<Url>,
+    pub url_private_download: Option<Url>,
+    pub permalink: Option<Url>,
+    pub permalink_public: Option<Url>,
+    pub alt_txt: Option<String>,
+    #[serde(default)]
+    pub channels: Vec<conversation::Id>,
+    #[serde(default)]
+    pub groups: Vec<conversation::Id>,
+    #[serde(default)]
+    pub ims: Vec<conversation::Id>,
+}
+
+/// Kinds of files `files.list` can filter by
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TypeFilter {
+    Spaces,
+    Snippets,
+    Images,
+    Gdocs,
+    Zips,
+    Pdfs,
+}
+
+#[derive(Clone, Default)]
+pub struct ListOptions {
+    pub channel: Option<conversation::Id>,
+    pub user: Option<user::Id>,
+    pub types: Vec<TypeFilter>,
+    pub ts_from: Option<OffsetDateTime>,
+    pub ts_to: Option<OffsetDateTime>,
+    pub limit: Limit,
+}
+
+// GET UPLOAD URL EXTERNAL
+/// Where to send a file's contents before completing the upload
+#[derive(Debug, Deserialize)]
+pub struct UploadUrl {
+    pub upload_url: Url,
+    pub file_id: Id,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum GetUploadUrlExternalResponse {
+    Ok(UploadUrl),
+    Error { error: String },
+}
+
+// COMPLETE UPLOAD EXTERNAL
+/// A file whose contents were already sent to its upload URL
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileUpload {
+    pub id: Id,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// Where to share files once they're uploaded. Files are kept private if
+/// `channel_id` isn't set.
+#[derive(Default)]
+pub struct CompleteUploadOptions {
+    pub channel_id: Option<conversation::Id>,
+    pub thread_ts: Option<OffsetDateTime>,
+    pub initial_comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CompleteUploadExternalResponse {
+    Ok { files: Vec<FileUpload> },
+    Error { error: String },
+}
+
+// REMOTE FILES
+/// A file hosted outside of Slack to add with `files.remote.add`
+pub struct NewRemoteFile {
+    pub external_id: ExternalId,
+    pub external_url: Url,
+    pub title: String,
+    pub filetype: Option<String>,
+    pub indexable_file_contents: Option<String>,
+    pub preview_image: Option<PreviewImage>,
+}
+
+/// Changes to a remote file. Anything left as `None` is kept as is.
+#[derive(Default)]
+pub struct RemoteFileUpdate {
+    pub external_url: Option<Url>,
+    pub title: Option<String>,
+    pub filetype: Option<String>,
+    pub indexable_file_contents: Option<String>,
+    pub preview_image: Option<PreviewImage>,
+}
+
+/// Image shown in place of a remote file's contents
+pub struct PreviewImage {
+    pub image: Vec<u8>,
+    pub file_name: String,
+}
+
+#[derive(Clone, Default)]
+pub struct RemoteListOptions {
+    pub channel: Option<conversation::Id>,
+    pub ts_from: Option<OffsetDateTime>,
+    pub ts_to: Option<OffsetDateTime>,
+    pub limit: Limit,
+}
+
+// INFO
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum InfoResponse {
+    Ok { file: Box<File> },
+    Error { error: String },
+}
+
+// LIST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ListResponse {
+    Ok { files: Vec<File>, paging: Paging },
+    Error { error: String },
+}
+
+// DELETE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DeleteResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// SHARED PUBLIC URL
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SharedPublicUrlResponse {
+    Ok { file: Box<File> },
+    Error { error: String },
+}
+
+// REVOKE PUBLIC URL
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RevokePublicUrlResponse {
+    Ok { file: Box<File> },
+    Error { error: String },
+}
+
+// REMOTE ADD
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoteAddResponse {
+    Ok { file: Box<File> },
+    Error { error: String },
+}
+
+// REMOTE INFO
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoteInfoResponse {
+    Ok { file: Box<File> },
+    Error { error: String },
+}
+
+// REMOTE LIST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoteListResponse {
+    Ok {
+        files: Vec<File>,
+        response_metadata: ResponseMetadata,
+    },
+    Error {
+        error: String,
+    },
+}
+
+// REMOTE REMOVE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoteRemoveResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// REMOTE SHARE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoteShareResponse {
+    Ok { file: Box<File> },
+    Error { error: String },
+}
+
+// REMOTE UPDATE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoteUpdateResponse {
+    Ok { file: Box<File> },
+    Error { error: String },
+}
+
+impl Id {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl ExternalId {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl NewRemoteFile {
+    pub fn new(external_id: ExternalId, external_url: Url, title: String) -> Self {
+        Self {
+            external_id,
+            external_url,
+            title,
+            filetype: None,
+            indexable_file_contents: None,
+            preview_image: None,
+        }
+    }
+
+    pub fn set_filetype(self, filetype: String) -> Self {
+        Self {
+            filetype: Some(filetype),
+            ..self
+        }
+    }
+
+    /// Text Slack uses to find the file when searching
+    pub fn set_indexable_file_contents(self, contents: String) -> Self {
+        Self {
+            indexable_file_contents: Some(contents),
+            ..self
+        }
+    }
+
+    pub fn set_preview_image(self, preview_image: PreviewImage) -> Self {
+        Self {
+            preview_image: Some(preview_image),
+            ..self
+        }
+    }
+
+    pub fn text_params(&self) -> Vec<(&'static str, String)> {
+        let mut opts = vec![
+            ("external_id", self.external_id.0.clone()),
+            ("external_url", self.external_url.to_string()),
+            ("title", self.title.clone()),
+        ];
+
+        if let Some(filetype) = &self.filetype {
+            opts.push(("filetype", filetype.clone()))
+        }
+
+        if let Some(contents) = &self.indexable_file_contents {
+            opts.push(("indexable_file_contents", contents.clone()))
+        }
+
+        opts
+    }
+}
+
+impl RemoteFileUpdate {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_external_url(self, external_url: Url) -> Self {
+        Self {
+            external_url: Some(external_url),
+            ..self
+        }
+    }
+
+    pub fn set_title(self, title: String) -> Self {
+        Self {
+            title: Some(title),
+            ..self
+        }
+    }
+
+    pub fn set_filetype(self, filetype: String) -> Self {
+        Self {
+            filetype: Some(filetype),
+            ..self
+        }
+    }
+
+    pub fn set_indexable_file_contents(self, contents: String) -> Self {
+        Self {
+            indexable_file_contents: Some(contents),
+            ..self
+        }
+    }
+
+    pub fn set_preview_image(self, preview_image: PreviewImage) -> Self {
+        Self {
+            preview_image: Some(preview_image),
+            ..self
+        }
+    }
+
+    pub fn text_params(&self) -> Vec<(&'static str, String)> {
+        let mut opts = Vec::new();
+
+        if let Some(external_url) = &self.external_url {
+            opts.push(("external_url", external_url.to_string()))
+        }
+
+        if let Some(title) = &self.title {
+            opts.push(("title", title.clone()))
+        }
+
+        if let Some(filetype) = &self.filetype {
+            opts.push(("filetype", filetype.clone()))
+        }
+
+        if let Some(contents) = &self.indexable_file_contents {
+            opts.push(("indexable_file_contents", contents.clone()))
+        }
+
+        opts
+    }
+}
+
+impl RemoteListOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_channel(self, channel: conversation::Id) -> Self {
+        Self {
+            channel: Some(channel),
+            ..self
+        }
+    }
+
+    pub fn set_ts_from(self, ts_from: OffsetDateTime) -> Self {
+        Self {
+            ts_from: Some(ts_from),
+            ..self
+        }
+    }
+
+    pub fn set_ts_to(self, ts_to: OffsetDateTime) -> Self {
+        Self {
+            ts_to: Some(ts_to),
+            ..self
+        }
+    }
+
+    pub fn set_limit(self, limit: Limit) -> Self {
+        Self { limit, ..self }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, String)> {
+        let mut opts = vec![("limit", self.limit.get().to_string())];
+
+        if let Some(channel) = &self.channel {
+            opts.push(("channel", channel.0.clone()))
+        }
+
+        if let Some(ts_from) = &self.ts_from {
+            opts.push(("ts_from", ts_from.unix_timestamp().to_string()))
+        }
+
+        if let Some(ts_to) = &self.ts_to {
+            opts.push(("ts_to", ts_to.unix_timestamp().to_string()))
+        }
+
+        opts
+    }
+}
+
+impl TypeFilter {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TypeFilter::Spaces => "spaces",
+            TypeFilter::Snippets => "snippets",
+            TypeFilter::Images => "images",
+            TypeFilter::Gdocs => "gdocs",
+            TypeFilter::Zips => "zips",
+            TypeFilter::Pdfs => "pdfs",
+        }
+    }
+}
+
+impl ListOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_channel(self, channel: conversation::Id) -> Self {
+        Self {
+            channel: Some(channel),
+            ..self
+        }
+    }
+
+    pub fn set_user(self, user: user::Id) -> Self {
+        Self {
+            user: Some(user),
+            ..self
+        }
+    }
+
+    pub fn include_type(self, filter: TypeFilter) -> Self {
+        let mut types = self.types;
+        types.push(filter);
+
+        Self { types, ..self }
+    }
+
+    /// Only lists files created after `ts_from`
+    pub fn set_ts_from(self, ts_from: OffsetDateTime) -> Self {
+        Self {
+            ts_from: Some(ts_from),
+            ..self
+        }
+    }
+
+    /// Only lists files created before `ts_to`
+    pub fn set_ts_to(self, ts_to: OffsetDateTime) -> Self {
+        Self {
+            ts_to: Some(ts_to),
+            ..self
+        }
+    }
+
+    pub fn set_limit(self, limit: Limit) -> Self {
+        Self { limit, ..self }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, String)> {
+        let mut opts = vec![("count", self.limit.get().to_string())];
+
+        if let Some(channel) = &self.channel {
+            opts.push(("channel", channel.0.clone()))
+        }
+
+        if let Some(user) = &self.user {
+            opts.push(("user", user.0.clone()))
+        }
+
+        if self.types.is_empty() {
+            opts.push(("types", "all".to_string()))
+        } else {
+            opts.push((
+                "types",
+                self.types
+                    .iter()
+                    .map(|t| t.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(","),
+            ))
+        }
+
+        if let Some(ts_from) = &self.ts_from {
+            opts.push(("ts_from", ts_from.unix_timestamp().to_string()))
+        }
+
+        if let Some(ts_to) = &self.ts_to {
+            opts.push(("ts_to", ts_to.unix_timestamp().to_string()))
+        }
+
+        opts
+    }
+}
+
+impl FileUpload {
+    pub fn new(id: Id) -> Self {
+        Self { id, title: None }
+    }
+
+    pub fn set_title(self, title: String) -> Self {
+        Self {
+            title: Some(title),
+            ..self
+        }
+    }
+}
+
+impl CompleteUploadOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_channel_id(self, channel_id: conversation::Id) -> Self {
+        Self {
+            channel_id: Some(channel_id),
+            ..self
+        }
+    }
+
+    /// Shares the files as a reply in a thread. Needs `channel_id` to be set.
+    pub fn set_thread_ts(self, thread_ts: OffsetDateTime) -> Self {
+        Self {
+            thread_ts: Some(thread_ts),
+            ..self
+        }
+    }
+
+    pub fn set_initial_comment(self, initial_comment: String) -> Self {
+        Self {
+            initial_comment: Some(initial_comment),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, String)> {
+        let mut opts = Vec::new();
+
+        if let Some(channel_id) = &self.channel_id {
+            opts.push(("channel_id", channel_id.0.clone()))
+        }
+
+        if let Some(thread_ts) = &self.thread_ts {
+            opts.push((
+                "thread_ts",
+                crate::offset_date_time_to_unix_ts_with_nano(thread_ts),
+            ))
+        }
+
+        if let Some(initial_comment) = &self.initial_comment {
+            opts.push(("initial_comment", initial_comment.clone()))
+        }
+
+        opts
+    }
+}
@@ -0,0 +1,625 @@
+use std::collections::HashMap;
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use time::{Date, Month, OffsetDateTime, Time};
+use url::Url;
+
+use crate::{
+    blocks::{Block, OptionGroup, SelectOption},
+    conversation, offset_date_time_from_unix_ts_with_nano, optional_offset_date_time_from_unix_ts,
+    optional_offset_date_time_from_unix_ts_with_nano, team, user,
+    views::{self, Modal, TriggerId, View},
+};
+
+/// How many options Slack shows in an external select
+pub const MAX_OPTIONS: usize = 100;
+
+#[derive(Debug, Error)]
+pub enum PayloadError {
+    #[error("request body has no `payload` field")]
+    MissingPayload,
+    #[error("failed to deserialize interactivity payload. reason: {0}")]
+    Deserialize(#[from] serde_json::Error),
+}
+
+/// What Slack sends to the interactivity request URL, as the JSON in the
+/// `payload` field of a form
+#[derive(Debug)]
+pub enum Payload {
+    BlockActions(Box<BlockActions>),
+    BlockSuggestion(Box<BlockSuggestion>),
+    MessageAction(Box<MessageAction>),
+    Shortcut(Box<Shortcut>),
+    ViewClosed(Box<ViewClosed>),
+    ViewSubmission(Box<ViewSubmission>),
+    /// A payload this crate doesn't have a type for, e.g.
+    /// `interactive_message`, as it was sent
+    Unknown(Value),
+}
+
+/// A user clicked or changed an interactive element in a message or view
+#[derive(Debug, Deserialize)]
+pub struct BlockActions {
+    pub user: User,
+    pub team: Option<Team>,
+    pub api_app_id: String,
+    pub container: Container,
+    pub trigger_id: TriggerId,
+    pub channel: Option<Channel>,
+    /// Set when the action happened in a message
+    pub message: Option<Message>,
+    /// Set when the action happened in a view
+    pub view: Option<Box<View>>,
+    /// Values of the message's input elements. The values of a view's are in
+    /// `view.state`.
+    #[serde(default)]
+    pub state: State,
+    pub response_url: Option<Url>,
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Action {
+    pub action_id: String,
+    pub block_id: String,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub action_ts: OffsetDateTime,
+    #[serde(flatten)]
+    pub value: ElementValue,
+}
+
+/// A user typed into an external select. Answered with the options to show.
+#[derive(Debug, Deserialize)]
+pub struct BlockSuggestion {
+    pub user: User,
+    pub team: Option<Team>,
+    pub api_app_id: String,
+    pub container: Container,
+    pub action_id: String,
+    pub block_id: String,
+    /// What the user has typed so far
+    pub value: String,
+    pub channel: Option<Channel>,
+    pub message: Option<Message>,
+    pub view: Option<Box<View>>,
+}
+
+/// A user ran one of the app's message shortcuts
+#[derive(Debug, Deserialize)]
+pub struct MessageAction {
+    pub callback_id: String,
+    pub trigger_id: TriggerId,
+    pub user: User,
+    pub team: Option<Team>,
+    pub channel: Channel,
+    pub message: Message,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub message_ts: OffsetDateTime,
+    pub response_url: Url,
+}
+
+/// A user ran one of the app's global shortcuts
+#[derive(Debug, Deserialize)]
+pub struct Shortcut {
+    pub callback_id: String,
+    pub trigger_id: TriggerId,
+    pub user: User,
+    pub team: Option<Team>,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts_with_nano")]
+    pub action_ts: Option<OffsetDateTime>,
+}
+
+/// A user closed a modal that was opened with `notify_on_close`
+#[derive(Debug, Deserialize)]
+pub struct ViewClosed {
+    pub user: User,
+    pub team: Option<Team>,
+    pub api_app_id: String,
+    pub view: Box<View>,
+    /// Whether the whole view stack was closed
+    #[serde(default)]
+    pub is_cleared: bool,
+}
+
+/// A user submitted a modal. The submitted values are in `view.state`.
+#[derive(Debug, Deserialize)]
+pub struct ViewSubmission {
+    pub user: User,
+    pub team: Option<Team>,
+    pub api_app_id: String,
+    pub trigger_id: Option<TriggerId>,
+    pub view: Box<View>,
+    /// Set when the modal has a `response_url_enabled` conversation select
+    #[serde(default)]
+    pub response_urls: Vec<ResponseUrl>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResponseUrl {
+    pub block_id: String,
+    pub action_id: String,
+    pub channel_id: conversation::Id,
+    pub response_url: Url,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct User {
+    pub id: user::Id,
+    pub username: Option<String>,
+    pub name: Option<String>,
+    pub team_id: Option<team::Id>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Team {
+    pub id: team::Id,
+    pub domain: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Channel {
+    pub id: conversation::Id,
+    pub name: Option<String>,
+}
+
+/// Where the interaction happened
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Container {
+    Message {
+        #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+        message_ts: OffsetDateTime,
+        channel_id: conversation::Id,
+        #[serde(default)]
+        is_ephemeral: bool,
+    },
+    View {
+        view_id: views::Id,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// The message an interaction happened in
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    pub user: Option<user::Id>,
+    pub bot_id: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    #[serde(rename = "ts")]
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")]
+    pub timestamp: OffsetDateTime,
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts_with_nano")]
+    pub thread_ts: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+}
+
+/// Current values of the input elements in a view or message
+#[derive(Debug, Default, Deserialize)]
+pub struct State {
+    /// Keyed by `block_id`, then by `action_id`
+    pub values: HashMap<String, HashMap<String, ElementValue>>,
+}
+
+/// The value of an interactive element. Anything not picked yet is `None` or
+/// empty.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ElementValue {
+    Button {
+        value: Option<String>,
+    },
+    ChannelsSelect {
+        selected_channel: Option<conversation::Id>,
+    },
+    Checkboxes {
+        #[serde(default)]
+        selected_options: Vec<SelectOption>,
+    },
+    ConversationsSelect {
+        selected_conversation: Option<conversation::Id>,
+    },
+    Datepicker {
+        #[serde(default)]
+        #[serde(deserialize_with = "optional_date")]
+        selected_date: Option<Date>,
+    },
+    Datetimepicker {
+        #[serde(default)]
+        #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts")]
+        selected_date_time: Option<OffsetDateTime>,
+    },
+    EmailTextInput {
+        value: Option<String>,
+    },
+    ExternalSelect {
+        selected_option: Option<SelectOption>,
+    },
+    MultiConversationsSelect {
+        #[serde(default)]
+        selected_conversations: Vec<conversation::Id>,
+    },
+    MultiExternalSelect {
+        #[serde(default)]
+        selected_options: Vec<SelectOption>,
+    },
+    MultiStaticSelect {
+        #[serde(default)]
+        selected_options: Vec<SelectOption>,
+    },
+    MultiUsersSelect {
+        #[serde(default)]
+        selected_users: Vec<user::Id>,
+    },
+    /// The number as typed, since it can be a decimal
+    NumberInput {
+        value: Option<String>,
+    },
+    Overflow {
+        selected_option: Option<SelectOption>,
+    },
+    PlainTextInput {
+        value: Option<String>,
+    },
+    RadioButtons {
+        selected_option: Option<SelectOption>,
+    },
+    /// The formatted text as a `rich_text` block
+    RichTextInput {
+        rich_text_value: Option<Value>,
+    },
+    StaticSelect {
+        selected_option: Option<SelectOption>,
+    },
+    Timepicker {
+        #[serde(default)]
+        #[serde(deserialize_with = "optional_time")]
+        selected_time: Option<Time>,
+    },
+    UrlTextInput {
+        value: Option<String>,
+    },
+    UsersSelect {
+        selected_user: Option<user::Id>,
+    },
+    /// An element this crate doesn't know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// What to answer a `view_submission` with. An empty `200 OK` closes the
+/// submitted modal, these do something else instead.
+#[derive(Debug, Serialize)]
+#[serde(tag = "response_action", rename_all = "snake_case")]
+pub enum ViewSubmissionResponse {
+    /// Keeps the modal open and shows the errors under their blocks
+    Errors { errors: ViewErrors },
+    /// Replaces the submitted modal
+    Update { view: Box<Modal> },
+    /// Pushes a modal on top of the submitted one
+    Push { view: Box<Modal> },
+    /// Closes every modal in the stack
+    Clear,
+}
+
+/// Validation errors for a submitted modal, keyed by the `block_id` of the
+/// input block they're shown under
+#[derive(Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct ViewErrors(HashMap<String, String>);
+
+/// What to answer a `block_suggestion` with, within 3 seconds. Slack shows at
+/// most 100 options.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum OptionsResponse {
+    Options { options: Vec<SelectOption> },
+    OptionGroups { option_groups: Vec<OptionGroup> },
+}
+
+impl Payload {
+    /// Parses the form encoded body of a request to the interactivity request
+    /// URL.
+    pub fn from_form(body: &[u8]) -> Result<Self, PayloadError> {
+        let payload = url::form_urlencoded::parse(body)
+            .find(|(key, _)| key == "payload")
+            .map(|(_, payload)| payload)
+            .ok_or(PayloadError::MissingPayload)?;
+
+        Ok(serde_json::from_str(&payload)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for Payload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        let kind = value
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| de::Error::missing_field("type"))?
+            .to_string();
+
+        let payload = match kind.as_str() {
+            "block_actions" => Box::deserialize(&value).map(Payload::BlockActions),
+            "block_suggestion" => Box::deserialize(&value).map(Payload::BlockSuggestion),
+            "message_action" => Box::deserialize(&value).map(Payload::MessageAction),
+            "shortcut" => Box::deserialize(&value).map(Payload::Shortcut),
+            "view_closed" => Box::deserialize(&value).map(Payload::ViewClosed),
+            "view_submission" => Box::deserialize(&value).map(Payload::ViewSubmission),
+            _ => return Ok(Payload::Unknown(value)),
+        };
+
+        payload.map_err(de::Error::custom)
+    }
+}
+
+impl ViewSubmissionResponse {
+    pub fn errors(errors: ViewErrors) -> Self {
+        ViewSubmissionResponse::Errors { errors }
+    }
+
+    pub fn update(view: Modal) -> Self {
+        ViewSubmissionResponse::Update {
+            view: Box::new(view),
+        }
+    }
+
+    pub fn push(view: Modal) -> Self {
+        ViewSubmissionResponse::Push {
+            view: Box::new(view),
+        }
+    }
+
+    pub fn clear() -> Self {
+        ViewSubmissionResponse::Clear
+    }
+}
+
+impl BlockSuggestion {
+    /// The options whose text or value starts with what the user has typed so
+    /// far, ignoring case
+    pub fn filter(&self, options: impl IntoIterator<Item = SelectOption>) -> Vec<SelectOption> {
+        filter_options(options, &self.value)
+    }
+}
+
+impl OptionsResponse {
+    pub fn options(options: Vec<SelectOption>) -> Self {
+        OptionsResponse::Options {
+            options: options.into_iter().take(MAX_OPTIONS).collect(),
+        }
+    }
+
+    /// Drops groups without options since Slack refuses empty groups. Like
+    /// the options in a group, Slack shows at most 100 groups.
+    pub fn option_groups(option_groups: Vec<OptionGroup>) -> Self {
+        OptionsResponse::OptionGroups {
+            option_groups: option_groups
+                .into_iter()
+                .filter(|group| !group.options.is_empty())
+                .take(MAX_OPTIONS)
+                .map(|group| OptionGroup {
+                    options: group.options.into_iter().take(MAX_OPTIONS).collect(),
+                    ..group
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ViewErrors {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds an error under the input block `block_id`. Adding another error
+    /// for the same block replaces it.
+    pub fn add(self, block_id: String, message: String) -> Self {
+        let mut errors = self.0;
+        errors.insert(block_id, message);
+
+        Self(errors)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, block_id: &str) -> Option<&str> {
+        self.0.get(block_id).map(String::as_str)
+    }
+}
+
+impl From<ViewErrors> for ViewSubmissionResponse {
+    fn from(errors: ViewErrors) -> Self {
+        ViewSubmissionResponse::Errors { errors }
+    }
+}
+
+impl State {
+    pub fn get(&self, block_id: &str, action_id: &str) -> Option<&ElementValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+
+    /// Text typed into a plain text, email, URL or number input. `None` when
+    /// it was left empty.
+    pub fn text(&self, block_id: &str, action_id: &str) -> Option<&str> {
+        match self.get(block_id, action_id)? {
+            ElementValue::PlainTextInput { value }
+            | ElementValue::EmailTextInput { value }
+            | ElementValue::UrlTextInput { value }
+            | ElementValue::NumberInput { value } => value.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The formatted text of a rich text input, as a `rich_text` block
+    pub fn rich_text(&self, block_id: &str, action_id: &str) -> Option<&Value> {
+        match self.get(block_id, action_id)? {
+            ElementValue::RichTextInput { rich_text_value } => rich_text_value.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The option picked in a single select, radio button group or overflow
+    /// menu
+    pub fn selected_option(&self, block_id: &str, action_id: &str) -> Option<&SelectOption> {
+        match self.get(block_id, action_id)? {
+            ElementValue::StaticSelect { selected_option }
+            | ElementValue::ExternalSelect { selected_option }
+            | ElementValue::RadioButtons { selected_option }
+            | ElementValue::Overflow { selected_option } => selected_option.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The options picked in a multi select or checkbox group
+    pub fn selected_options(&self, block_id: &str, action_id: &str) -> &[SelectOption] {
+        match self.get(block_id, action_id) {
+            Some(
+                ElementValue::MultiStaticSelect { selected_options }
+                | ElementValue::MultiExternalSelect { selected_options }
+                | ElementValue::Checkboxes { selected_options },
+            ) => selected_options,
+            _ => &[],
+        }
+    }
+
+    pub fn selected_user(&self, block_id: &str, action_id: &str) -> Option<&user::Id> {
+        match self.get(block_id, action_id)? {
+            ElementValue::UsersSelect { selected_user } => selected_user.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn selected_users(&self, block_id: &str, action_id: &str) -> &[user::Id] {
+        match self.get(block_id, action_id) {
+            Some(ElementValue::MultiUsersSelect { selected_users }) => selected_users,
+            _ => &[],
+        }
+    }
+
+    /// The conversation picked in a conversations or channels select
+    pub fn selected_conversation(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Option<&conversation::Id> {
+        match self.get(block_id, action_id)? {
+            ElementValue::ConversationsSelect {
+                selected_conversation,
+            } => selected_conversation.as_ref(),
+            ElementValue::ChannelsSelect { selected_channel } => selected_channel.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn selected_conversations(&self, block_id: &str, action_id: &str) -> &[conversation::Id] {
+        match self.get(block_id, action_id) {
+            Some(ElementValue::MultiConversationsSelect {
+                selected_conversations,
+            }) => selected_conversations,
+            _ => &[],
+        }
+    }
+
+    pub fn selected_date(&self, block_id: &str, action_id: &str) -> Option<Date> {
+        match self.get(block_id, action_id)? {
+            ElementValue::Datepicker { selected_date } => *selected_date,
+            _ => None,
+        }
+    }
+
+    pub fn selected_time(&self, block_id: &str, action_id: &str) -> Option<Time> {
+        match self.get(block_id, action_id)? {
+            ElementValue::Timepicker { selected_time } => *selected_time,
+            _ => None,
+        }
+    }
+
+    pub fn selected_date_time(&self, block_id: &str, action_id: &str) -> Option<OffsetDateTime> {
+        match self.get(block_id, action_id)? {
+            ElementValue::Datetimepicker { selected_date_time } => *selected_date_time,
+            _ => None,
+        }
+    }
+}
+
+/// Keeps the options whose text or value starts with `prefix`, ignoring case,
+/// up to the 100 Slack shows. An empty prefix keeps every option.
+pub fn filter_options(
+    options: impl IntoIterator<Item = SelectOption>,
+    prefix: &str,
+) -> Vec<SelectOption> {
+    let prefix = prefix.trim().to_lowercase();
+
+    options
+        .into_iter()
+        .filter(|option| {
+            option.text.text().to_lowercase().starts_with(&prefix)
+                || option.value.to_lowercase().starts_with(&prefix)
+        })
+        .take(MAX_OPTIONS)
+        .collect()
+}
+
+/// Deserializes a `YYYY-MM-DD` date.
+fn optional_date<'de, D>(deserializer: D) -> Result<Option<Date>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(date) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    let invalid = || de::Error::invalid_value(de::Unexpected::Str(&date), &"a YYYY-MM-DD date");
+
+    let mut parts = date.splitn(3, '-').map(str::parse::<i32>);
+
+    let (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+
+    let month = u8::try_from(month)
+        .ok()
+        .and_then(|month| Month::try_from(month).ok())
+        .ok_or_else(invalid)?;
+
+    let day = u8::try_from(day).map_err(|_| invalid())?;
+
+    Date::from_calendar_date(year, month, day)
+        .map(Some)
+        .map_err(|_| invalid())
+}
+
+/// Deserializes an `HH:mm` time.
+fn optional_time<'de, D>(deserializer: D) -> Result<Option<Time>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(time) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    let invalid = || de::Error::invalid_value(de::Unexpected::Str(&time), &"an HH:mm time");
+
+    let (hour, minute) = time.split_once(':').ok_or_else(invalid)?;
+    let hour = hour.parse::<u8>().map_err(|_| invalid())?;
+    let minute = minute.parse::<u8>().map_err(|_| invalid())?;
+
+    Time::from_hms(hour, minute, 0)
+        .map(Some)
+        .map_err(|_| invalid())
+}
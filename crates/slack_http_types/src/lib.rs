@@ -5,17 +5,32 @@ use std::num::ParseIntError;
 use serde::{de, Deserialize, Deserializer};
 use time::OffsetDateTime;
 
+pub mod blocks;
+pub mod bookmarks;
 pub mod chat;
 pub mod client;
+pub mod commands;
 pub mod conversation;
+pub mod dnd;
 pub mod emoji;
 pub mod error;
+pub mod events;
+pub mod files;
+pub mod interactivity;
 pub mod oauth;
 pub mod openid;
 pub mod option;
 pub mod page;
+pub mod pins;
+pub mod reactions;
+pub mod reminders;
+pub mod response_url;
+pub mod search;
 pub mod team;
 pub mod user;
+pub mod usergroups;
+pub mod views;
+pub mod webhooks;
 
 /// Deserializes a UNIX timestamp with milliseconds into an `OffsetDateTime`.
 pub fn offset_date_time_from_unix_ts<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
@@ -26,6 +41,24 @@ where
     OffsetDateTime::from_unix_timestamp(ts).map_err(de::Error::custom)
 }
 
+/// Deserializes an optional UNIX timestamp into an `Option<OffsetDateTime>`.
+/// Slack uses `0` to mean "not set", so that is treated the same as `null`.
+pub fn optional_offset_date_time_from_unix_ts<'de, D>(
+    deserializer: D,
+) -> Result<Option<OffsetDateTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let ts: Option<i64> = Deserialize::deserialize(deserializer)?;
+
+    match ts {
+        None | Some(0) => Ok(None),
+        Some(ts) => OffsetDateTime::from_unix_timestamp(ts)
+            .map(Some)
+            .map_err(de::Error::custom),
+    }
+}
+
 /// Deserializes a UNIX timestamp with milliseconds into an `OffsetDateTime`.
 pub fn offset_date_time_from_unix_ts_with_nano<'de, D>(
     deserializer: D,
@@ -53,3 +86,27 @@ where
 
     OffsetDateTime::from_unix_timestamp_nanos(ts).map_err(de::Error::custom)
 }
+
+/// Deserializes an optional UNIX timestamp with milliseconds into an
+/// `Option<OffsetDateTime>`.
+pub fn optional_offset_date_time_from_unix_ts_with_nano<'de, D>(
+    deserializer: D,
+) -> Result<Option<OffsetDateTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Ts(
+        #[serde(deserialize_with = "offset_date_time_from_unix_ts_with_nano")] OffsetDateTime,
+    );
+
+    let ts: Option<Ts> = Deserialize::deserialize(deserializer)?;
+
+    Ok(ts.map(|Ts(ts)| ts))
+}
+
+/// Formats an `OffsetDateTime` the way Slack formats message timestamps, e.g.
+/// `1716700028.123456`. This is what Slack uses to identify a message.
+pub fn offset_date_time_to_unix_ts_with_nano(ts: &OffsetDateTime) -> String {
+    format!("{}.{:06}", ts.unix_timestamp(), ts.microsecond())
+}
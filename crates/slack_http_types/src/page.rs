@@ -14,6 +14,21 @@ pub struct ResponseMetadata {
     pub next_cursor: String,
 }
 
+/// Results from endpoints that paginate by page number instead of a cursor
+#[derive(Debug)]
+pub struct NumberedPage<T> {
+    pub results: Vec<T>,
+    pub paging: Paging,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct Paging {
+    pub count: u32,
+    pub total: u32,
+    pub page: u32,
+    pub pages: u32,
+}
+
 impl<T> Page<T> {
     pub fn results(&self) -> &[T] {
         self.results.as_ref()
@@ -28,6 +43,29 @@ impl<T> Page<T> {
     }
 }
 
+impl<T> NumberedPage<T> {
+    pub fn results(&self) -> &[T] {
+        self.results.as_ref()
+    }
+
+    pub fn paging(&self) -> &Paging {
+        &self.paging
+    }
+
+    /// The page after this one, if there is one
+    pub fn next_page(&self) -> Option<u32> {
+        if self.paging.page < self.paging.pages {
+            Some(self.paging.page + 1)
+        } else {
+            None
+        }
+    }
+
+    pub fn new(results: Vec<T>, paging: Paging) -> Self {
+        Self { results, paging }
+    }
+}
+
 impl From<ResponseMetadata> for Cursor {
     fn from(value: ResponseMetadata) -> Self {
         if value.next_cursor.is_empty() {
@@ -68,4 +106,9 @@ impl Limit {
     pub fn get(&self) -> u16 {
         self.0
     }
+
+    /// Lowers the limit to `max` for endpoints that allow less than 1000
+    pub(crate) fn at_most(self, max: u16) -> Self {
+        Self(self.0.min(max))
+    }
 }
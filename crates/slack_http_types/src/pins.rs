@@ -0,0 +1,67 @@
+use serde::Deserialize;
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::{conversation, files, offset_date_time_from_unix_ts, user};
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PinnedItem {
+    Message {
+        channel: conversation::Id,
+        #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+        created: OffsetDateTime,
+        created_by: user::Id,
+        message: PinnedMessage,
+    },
+    File {
+        #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+        created: OffsetDateTime,
+        created_by: user::Id,
+        file: PinnedFile,
+    },
+    /// A pin this crate doesn't know about, or that doesn't match its type, as
+    /// it was sent
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PinnedMessage {
+    #[serde(rename = "ts")]
+    #[serde(deserialize_with = "crate::offset_date_time_from_unix_ts_with_nano")]
+    pub timestamp: OffsetDateTime,
+    #[serde(default)]
+    pub text: String,
+    pub user: Option<user::Id>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PinnedFile {
+    pub id: files::Id,
+    pub name: Option<String>,
+}
+
+// ADD
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AddResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// REMOVE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoveResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// LIST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ListResponse {
+    Ok { items: Vec<PinnedItem> },
+    Error { error: String },
+}
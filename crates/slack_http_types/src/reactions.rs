@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{conversation, files, page::ResponseMetadata, user};
+
+/// Name of the emoji used in a reaction, without the surrounding colons
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Name(pub String);
+
+#[derive(Debug, Deserialize)]
+pub struct Reaction {
+    pub name: Name,
+    pub count: u32,
+    /// May not list everyone who reacted. Use `count` for the total.
+    #[serde(default)]
+    pub users: Vec<user::Id>,
+}
+
+/// Something that can be reacted to
+pub enum Item {
+    Message {
+        channel: conversation::Id,
+        timestamp: OffsetDateTime,
+    },
+    File(files::Id),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReactedItem {
+    Message {
+        channel: conversation::Id,
+        message: ReactedMessage,
+    },
+    File {
+        file: ReactedFile,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReactedMessage {
+    #[serde(rename = "ts")]
+    #[serde(deserialize_with = "crate::offset_date_time_from_unix_ts_with_nano")]
+    pub timestamp: OffsetDateTime,
+    #[serde(default)]
+    pub text: String,
+    pub user: Option<user::Id>,
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReactedFile {
+    pub id: files::Id,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+}
+
+// ADD
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AddResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// REMOVE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoveResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// GET
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum GetResponse {
+    Ok(ReactedItem),
+    Error { error: String },
+}
+
+// LIST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ListResponse {
+    Ok {
+        items: Vec<ReactedItem>,
+        response_metadata: ResponseMetadata,
+    },
+    Error {
+        error: String,
+    },
+}
+
+impl Name {
+    /// Strips the colons off names like `:white_check_mark:`
+    pub fn new(name: &str) -> Self {
+        Self(name.trim_matches(':').to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// The name without a skin tone, e.g. `thumbsup` for
+    /// `thumbsup::skin-tone-2`
+    pub fn base(&self) -> &str {
+        self.0.split("::").next().unwrap_or(self.0.as_str())
+    }
+
+    /// Checks if this is one of the workspace's custom emojis, using the map
+    /// returned by `emoji::list`.
+    pub fn is_custom(&self, emojis: &HashMap<String, String>) -> bool {
+        emojis.contains_key(self.base())
+    }
+}
+
+impl Item {
+    pub fn query_params(&self) -> Vec<(&str, String)> {
+        match self {
+            Item::Message { channel, timestamp } => vec![
+                ("channel", channel.0.clone()),
+                (
+                    "timestamp",
+                    crate::offset_date_time_to_unix_ts_with_nano(timestamp),
+                ),
+            ],
+            Item::File(file_id) => vec![("file", file_id.0.clone())],
+        }
+    }
+}
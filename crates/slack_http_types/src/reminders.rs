@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize, Serializer};
+use time::{OffsetDateTime, Weekday};
+
+use crate::{optional_offset_date_time_from_unix_ts, team, user};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Id(pub String);
+
+#[derive(Debug, Deserialize)]
+pub struct Reminder {
+    pub id: Id,
+    pub creator: user::Id,
+    pub user: user::Id,
+    pub text: String,
+    pub recurring: bool,
+    /// Not set for recurring reminders
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts")]
+    pub time: Option<OffsetDateTime>,
+    /// When the reminder was marked as complete, if it was
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_offset_date_time_from_unix_ts")]
+    pub complete_ts: Option<OffsetDateTime>,
+}
+
+/// When a reminder should fire
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum When {
+    At(OffsetDateTime),
+    /// Anything Slack understands, e.g. "in 15 minutes" or "every Thursday"
+    Natural(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "frequency", rename_all = "lowercase")]
+pub enum Recurrence {
+    Daily,
+    Weekly {
+        #[serde(serialize_with = "lowercase_weekdays")]
+        weekdays: Vec<Weekday>,
+    },
+    Monthly,
+    Yearly,
+}
+
+/// A reminder to create with `reminders.add`
+pub struct NewReminder {
+    pub text: String,
+    pub time: When,
+    pub user: Option<user::Id>,
+    pub recurrence: Option<Recurrence>,
+    pub team_id: Option<team::Id>,
+}
+
+// ADD
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AddResponse {
+    Ok { reminder: Reminder },
+    Error { error: String },
+}
+
+// COMPLETE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CompleteResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// DELETE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DeleteResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// INFO
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum InfoResponse {
+    Ok { reminder: Reminder },
+    Error { error: String },
+}
+
+// LIST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ListResponse {
+    Ok { reminders: Vec<Reminder> },
+    Error { error: String },
+}
+
+impl Id {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl When {
+    pub fn query_param(&self) -> String {
+        match self {
+            When::At(time) => time.unix_timestamp().to_string(),
+            When::Natural(time) => time.clone(),
+        }
+    }
+}
+
+impl NewReminder {
+    pub fn new(text: String, time: When) -> Self {
+        Self {
+            text,
+            time,
+            user: None,
+            recurrence: None,
+            team_id: None,
+        }
+    }
+
+    /// Who to remind. Defaults to the authed user.
+    pub fn set_user(self, user: user::Id) -> Self {
+        Self {
+            user: Some(user),
+            ..self
+        }
+    }
+
+    pub fn set_recurrence(self, recurrence: Recurrence) -> Self {
+        Self {
+            recurrence: Some(recurrence),
+            ..self
+        }
+    }
+
+    pub fn set_team_id(self, team_id: team::Id) -> Self {
+        Self {
+            team_id: Some(team_id),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self) -> Result<Vec<(&str, String)>, serde_json::Error> {
+        let mut opts = vec![
+            ("text", self.text.clone()),
+            ("time", self.time.query_param()),
+        ];
+
+        if let Some(user) = &self.user {
+            opts.push(("user", user.0.clone()))
+        }
+
+        if let Some(recurrence) = &self.recurrence {
+            opts.push(("recurrence", serde_json::to_string(recurrence)?))
+        }
+
+        if let Some(team_id) = &self.team_id {
+            opts.push(("team_id", team_id.0.clone()))
+        }
+
+        Ok(opts)
+    }
+}
+
+fn lowercase_weekdays<S>(weekdays: &[Weekday], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(
+        weekdays
+            .iter()
+            .map(|weekday| weekday.to_string().to_lowercase()),
+    )
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{blocks::Block, chat::MessageOptions, commands::ResponseType};
+
+/// A message sent to the `response_url` of a slash command or interaction
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseMessage {
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_type: Option<ResponseType>,
+    pub replace_original: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    #[serde(flatten)]
+    pub opts: MessageOptions,
+}
+
+// POST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PostResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+impl ResponseMessage {
+    pub fn new(text: String) -> Self {
+        Self {
+            text,
+            blocks: Vec::new(),
+            response_type: None,
+            replace_original: false,
+            thread_ts: None,
+            opts: MessageOptions::default(),
+        }
+    }
+
+    /// When there are blocks, the text is only used for notifications
+    pub fn set_blocks(self, blocks: Vec<Block>) -> Self {
+        Self { blocks, ..self }
+    }
+
+    /// Slack treats the message as ephemeral when this isn't set
+    pub fn set_response_type(self, response_type: ResponseType) -> Self {
+        Self {
+            response_type: Some(response_type),
+            ..self
+        }
+    }
+
+    /// Replaces the message the interaction came from instead of posting a
+    /// new one
+    pub fn set_replace_original(self, replace_original: bool) -> Self {
+        Self {
+            replace_original,
+            ..self
+        }
+    }
+
+    pub fn set_thread_ts(self, thread_ts: &OffsetDateTime) -> Self {
+        Self {
+            thread_ts: Some(crate::offset_date_time_to_unix_ts_with_nano(thread_ts)),
+            ..self
+        }
+    }
+
+    pub fn set_opts(self, opts: MessageOptions) -> Self {
+        Self { opts, ..self }
+    }
+}
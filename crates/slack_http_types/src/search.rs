@@ -0,0 +1,193 @@
+use serde::Deserialize;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::{
+    conversation,
+    files::File,
+    page::{Limit, NumberedPage, Paging},
+    team, user,
+};
+
+/// Marks the start of a highlighted term when `highlight` is on
+pub const HIGHLIGHT_START: char = '\u{e000}';
+/// Marks the end of a highlighted term when `highlight` is on
+pub const HIGHLIGHT_END: char = '\u{e001}';
+/// Most results Slack returns per page
+pub const MAX_COUNT: u16 = 100;
+
+#[derive(Clone)]
+pub struct SearchOptions {
+    pub query: String,
+    pub sort: Sort,
+    pub sort_dir: SortDirection,
+    pub highlight: bool,
+    pub limit: Limit,
+    pub team_id: Option<team::Id>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Sort {
+    #[default]
+    Score,
+    Timestamp,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    #[default]
+    Descending,
+}
+
+/// A message matching a search query
+#[derive(Debug, Deserialize)]
+pub struct MessageMatch {
+    pub iid: Option<String>,
+    pub team: Option<team::Id>,
+    pub channel: MatchChannel,
+    pub user: Option<user::Id>,
+    pub username: Option<String>,
+    #[serde(rename = "ts")]
+    #[serde(deserialize_with = "crate::offset_date_time_from_unix_ts_with_nano")]
+    pub timestamp: OffsetDateTime,
+    pub text: String,
+    pub permalink: Url,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MatchChannel {
+    pub id: conversation::Id,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub is_mpim: bool,
+}
+
+/// Results of `search.all`. Both kinds are paged together by the same page
+/// number.
+#[derive(Debug)]
+pub struct AllResults {
+    pub messages: NumberedPage<MessageMatch>,
+    pub files: NumberedPage<File>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Matches<T> {
+    pub total: u32,
+    pub paging: Paging,
+    pub matches: Vec<T>,
+}
+
+// MESSAGES
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MessagesResponse {
+    Ok { messages: Matches<MessageMatch> },
+    Error { error: String },
+}
+
+// FILES
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum FilesResponse {
+    Ok { files: Matches<File> },
+    Error { error: String },
+}
+
+// ALL
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AllResponse {
+    Ok {
+        messages: Matches<MessageMatch>,
+        files: Matches<File>,
+    },
+    Error {
+        error: String,
+    },
+}
+
+impl SearchOptions {
+    pub fn new(query: String) -> Self {
+        Self {
+            query,
+            sort: Default::default(),
+            sort_dir: Default::default(),
+            highlight: false,
+            limit: Default::default(),
+            team_id: None,
+        }
+    }
+
+    pub fn set_sort(self, sort: Sort, sort_dir: SortDirection) -> Self {
+        Self {
+            sort,
+            sort_dir,
+            ..self
+        }
+    }
+
+    /// Wraps matching terms in `HIGHLIGHT_START` and `HIGHLIGHT_END`
+    pub fn set_highlight(self, highlight: bool) -> Self {
+        Self { highlight, ..self }
+    }
+
+    /// Results per page. Anything over `MAX_COUNT` is lowered to it.
+    pub fn set_limit(self, limit: Limit) -> Self {
+        Self {
+            limit: limit.at_most(MAX_COUNT),
+            ..self
+        }
+    }
+
+    /// Needed when searching with an org level token
+    pub fn set_team_id(self, team_id: team::Id) -> Self {
+        Self {
+            team_id: Some(team_id),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self, page: u32) -> Vec<(&str, String)> {
+        let mut opts = vec![
+            ("query", self.query.clone()),
+            ("sort", self.sort.as_str().to_string()),
+            ("sort_dir", self.sort_dir.as_str().to_string()),
+            ("highlight", self.highlight.to_string()),
+            ("count", self.limit.get().to_string()),
+            ("page", page.to_string()),
+        ];
+
+        if let Some(team_id) = &self.team_id {
+            opts.push(("team_id", team_id.0.clone()))
+        }
+
+        opts
+    }
+}
+
+impl Sort {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Sort::Score => "score",
+            Sort::Timestamp => "timestamp",
+        }
+    }
+}
+
+impl SortDirection {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SortDirection::Ascending => "asc",
+            SortDirection::Descending => "desc",
+        }
+    }
+}
+
+impl<T> From<Matches<T>> for NumberedPage<T> {
+    fn from(value: Matches<T>) -> Self {
+        NumberedPage::new(value.matches, value.paging)
+    }
+}
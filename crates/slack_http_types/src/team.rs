@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 use url::Url;
 
+use crate::page::ResponseMetadata;
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(transparent)]
 pub struct Id(pub String);
@@ -26,3 +29,45 @@ pub enum InfoResponse {
     Ok { team: Team },
     Error { error: String },
 }
+
+// EXTERNAL TEAMS
+/// An organization connected to this team through Slack Connect
+#[derive(Debug, Deserialize)]
+pub struct ExternalTeam {
+    pub team_id: Id,
+    pub team_name: String,
+    pub team_domain: Option<String>,
+    #[serde(default)]
+    pub public_channel_count: u32,
+    #[serde(default)]
+    pub private_channel_count: u32,
+    #[serde(default)]
+    pub im_channel_count: u32,
+    #[serde(default)]
+    pub mpim_channel_count: u32,
+    #[serde(default)]
+    pub connected_workspaces: Vec<ConnectedWorkspace>,
+    pub connection_status: Option<String>,
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::optional_offset_date_time_from_unix_ts")]
+    pub last_active_timestamp: Option<OffsetDateTime>,
+    pub is_sponsored: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConnectedWorkspace {
+    pub workspace_id: Id,
+    pub workspace_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ExternalTeamsListResponse {
+    Ok {
+        organizations: Vec<ExternalTeam>,
+        response_metadata: ResponseMetadata,
+    },
+    Error {
+        error: String,
+    },
+}
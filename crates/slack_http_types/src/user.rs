@@ -1,46 +1,136 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, IgnoredAny},
+    Deserialize, Deserializer, Serialize,
+};
+use time::OffsetDateTime;
 use url::Url;
 
-use crate::page::ResponseMetadata;
+use crate::{page::ResponseMetadata, team};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(transparent)]
 pub struct Id(pub String);
 
+/// A Slack user. Bots, deleted users and Enterprise Grid users tend to leave
+/// out fields, so anything Slack doesn't always send is optional. Profile
+/// fields are `None` when Slack leaves them out and `Some("")` when it sends
+/// them empty, which is how it marks them as not set.
 #[derive(Debug, Deserialize)]
 pub struct User {
     pub id: Id,
+    pub team_id: Option<team::Id>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
     pub deleted: bool,
+    pub color: Option<String>,
     pub is_admin: Option<bool>,
     pub is_owner: Option<bool>,
+    pub is_primary_owner: Option<bool>,
+    #[serde(default)]
     pub is_bot: bool,
+    #[serde(default)]
     pub is_app_user: bool,
+    pub is_email_confirmed: Option<bool>,
     pub profile: Profile,
     pub is_restricted: Option<bool>,
     pub is_ultra_restricted: Option<bool>,
+    pub enterprise_user: Option<EnterpriseUser>,
     pub tz: Option<String>,
     pub tz_label: Option<String>,
     pub tz_offset: Option<i64>,
+    pub locale: Option<String>,
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::optional_offset_date_time_from_unix_ts")]
+    pub updated: Option<OffsetDateTime>,
+    pub who_can_share_contact_card: Option<String>,
+    /// Anything Slack sends that isn't modeled above
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EnterpriseUser {
+    pub id: Id,
+    pub enterprise_id: String,
+    pub enterprise_name: String,
+    #[serde(default)]
+    pub is_admin: bool,
+    #[serde(default)]
+    pub is_owner: bool,
+    #[serde(default)]
+    pub teams: Vec<team::Id>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct Profile {
-    pub image_24: Url,
-    pub image_32: Url,
-    pub image_48: Url,
-    pub image_72: Url,
-    pub image_192: Url,
-    pub image_512: Url,
-    pub avatar_hash: String,
-    pub status_text: String,
-    pub status_emoji: String,
-    pub display_name: String,
-    pub display_name_normalized: String,
-    pub real_name: String,
-    pub real_name_normalized: String,
+    pub image_24: Option<Url>,
+    pub image_32: Option<Url>,
+    pub image_48: Option<Url>,
+    pub image_72: Option<Url>,
+    pub image_192: Option<Url>,
+    pub image_512: Option<Url>,
+    pub avatar_hash: Option<String>,
+    pub status_text: Option<String>,
+    pub status_emoji: Option<String>,
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::optional_offset_date_time_from_unix_ts")]
+    pub status_expiration: Option<OffsetDateTime>,
+    pub display_name: Option<String>,
+    pub display_name_normalized: Option<String>,
+    pub real_name: Option<String>,
+    pub real_name_normalized: Option<String>,
     pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub title: Option<String>,
+    pub phone: Option<String>,
+    pub pronouns: Option<String>,
+    /// Custom profile fields, keyed by field ID
+    #[serde(default)]
+    #[serde(deserialize_with = "profile_fields")]
+    pub fields: HashMap<String, ProfileField>,
+    /// Anything Slack sends that isn't modeled above
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ProfileField {
+    pub value: String,
+    #[serde(default)]
+    pub alt: String,
+}
+
+/// Fields to change with `users.profile.set`. Anything left as `None` is kept
+/// as is.
+#[derive(Debug, Default, Serialize)]
+pub struct ProfileUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub real_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "time::serde::timestamp::option")]
+    pub status_expiration: Option<OffsetDateTime>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub fields: HashMap<String, ProfileField>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -55,12 +145,232 @@ pub enum ListResponse {
     },
 }
 
+// INFO
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum InfoResponse {
+    Ok { user: Box<User> },
+    Error { error: String },
+}
+
+// LOOKUP BY EMAIL
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum LookupByEmailResponse {
+    Ok { user: Box<User> },
+    Error { error: String },
+}
+
+// PROFILE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ProfileGetResponse {
+    Ok { profile: Box<Profile> },
+    Error { error: String },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ProfileSetResponse {
+    Ok { profile: Box<Profile> },
+    Error { error: String },
+}
+
+// PRESENCE
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PresenceStatus {
+    Active,
+    Away,
+}
+
+/// A user's presence. Everything besides `presence` is only sent when asking
+/// about the authed user.
+#[derive(Debug, Deserialize)]
+pub struct Presence {
+    pub presence: PresenceStatus,
+    pub online: Option<bool>,
+    pub auto_away: Option<bool>,
+    pub manual_away: Option<bool>,
+    pub connection_count: Option<u32>,
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::optional_offset_date_time_from_unix_ts")]
+    pub last_activity: Option<OffsetDateTime>,
+}
+
+/// Presence that can be set with `users.setPresence`. `Auto` lets Slack decide
+/// based on the user's activity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SetPresence {
+    Auto,
+    Away,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum GetPresenceResponse {
+    Ok(Presence),
+    Error { error: String },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SetPresenceResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// SET PHOTO
+/// Square region of the uploaded image to use as the profile photo, in pixels
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PhotoCrop {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SetPhotoResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+// DELETE PHOTO
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DeletePhotoResponse {
+    Error { error: String },
+    Ok { ok: bool },
+}
+
+impl SetPresence {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SetPresence::Auto => "auto",
+            SetPresence::Away => "away",
+        }
+    }
+}
+
 impl Id {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
 }
 
+impl ProfileUpdate {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_display_name(self, display_name: String) -> Self {
+        Self {
+            display_name: Some(display_name),
+            ..self
+        }
+    }
+
+    pub fn set_real_name(self, real_name: String) -> Self {
+        Self {
+            real_name: Some(real_name),
+            ..self
+        }
+    }
+
+    pub fn set_first_name(self, first_name: String) -> Self {
+        Self {
+            first_name: Some(first_name),
+            ..self
+        }
+    }
+
+    pub fn set_last_name(self, last_name: String) -> Self {
+        Self {
+            last_name: Some(last_name),
+            ..self
+        }
+    }
+
+    pub fn set_title(self, title: String) -> Self {
+        Self {
+            title: Some(title),
+            ..self
+        }
+    }
+
+    pub fn set_phone(self, phone: String) -> Self {
+        Self {
+            phone: Some(phone),
+            ..self
+        }
+    }
+
+    pub fn set_pronouns(self, pronouns: String) -> Self {
+        Self {
+            pronouns: Some(pronouns),
+            ..self
+        }
+    }
+
+    /// Sets the user's status. `status_emoji` is the emoji name without the
+    /// colons, and `expiration` of `None` keeps the status until it's cleared.
+    pub fn set_status(
+        self,
+        status_text: String,
+        status_emoji: String,
+        expiration: Option<OffsetDateTime>,
+    ) -> Self {
+        let status_emoji = format!(":{status_emoji}:");
+
+        Self {
+            status_text: Some(status_text),
+            status_emoji: Some(status_emoji),
+            status_expiration: Some(expiration.unwrap_or(OffsetDateTime::UNIX_EPOCH)),
+            ..self
+        }
+    }
+
+    pub fn clear_status(self) -> Self {
+        Self {
+            status_text: Some(String::new()),
+            status_emoji: Some(String::new()),
+            status_expiration: Some(OffsetDateTime::UNIX_EPOCH),
+            ..self
+        }
+    }
+
+    pub fn set_field(self, field_id: String, value: String, alt: String) -> Self {
+        let mut fields = self.fields;
+        fields.insert(field_id, ProfileField { value, alt });
+
+        Self { fields, ..self }
+    }
+}
+
+/// Slack sends an empty array instead of an empty object when there are no
+/// custom fields set.
+fn profile_fields<'de, D>(deserializer: D) -> Result<HashMap<String, ProfileField>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Fields {
+        Map(HashMap<String, ProfileField>),
+        Empty(Vec<IgnoredAny>),
+    }
+
+    match Option::<Fields>::deserialize(deserializer)? {
+        Some(Fields::Map(fields)) => Ok(fields),
+        Some(Fields::Empty(fields)) if fields.is_empty() => Ok(HashMap::new()),
+        Some(Fields::Empty(_)) => Err(de::Error::custom(
+            "expected custom profile fields to be an object",
+        )),
+        None => Ok(HashMap::new()),
+    }
+}
+
 impl Display for Id {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -0,0 +1,346 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+use time::OffsetDateTime;
+
+use crate::{conversation, offset_date_time_from_unix_ts, team, user};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Id(pub String);
+
+#[derive(Debug, Deserialize)]
+pub struct Usergroup {
+    pub id: Id,
+    pub team_id: team::Id,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub handle: String,
+    #[serde(default)]
+    pub is_external: bool,
+    /// Set for groups Slack manages itself, e.g. `admin` or `owner`
+    pub auto_type: Option<String>,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_create: OffsetDateTime,
+    #[serde(deserialize_with = "offset_date_time_from_unix_ts")]
+    pub date_update: OffsetDateTime,
+    /// Set when the group has been disabled
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::optional_offset_date_time_from_unix_ts")]
+    pub date_delete: Option<OffsetDateTime>,
+    pub created_by: Option<user::Id>,
+    pub updated_by: Option<user::Id>,
+    pub deleted_by: Option<user::Id>,
+    pub prefs: Prefs,
+    /// Only included when users are requested
+    pub users: Option<Vec<user::Id>>,
+    /// Only included when the count is requested
+    #[serde(default)]
+    #[serde(deserialize_with = "optional_count")]
+    pub user_count: Option<u32>,
+}
+
+/// Channels members of the group are added to by default
+#[derive(Debug, Deserialize)]
+pub struct Prefs {
+    #[serde(default)]
+    pub channels: Vec<conversation::Id>,
+    #[serde(default)]
+    pub groups: Vec<conversation::Id>,
+}
+
+#[derive(Clone)]
+pub struct NewUsergroup {
+    pub name: String,
+    pub handle: Option<String>,
+    pub description: Option<String>,
+    pub channels: Vec<conversation::Id>,
+    pub team_id: Option<team::Id>,
+}
+
+/// Changes to a user group. Anything left as `None` is kept as is.
+#[derive(Clone, Default)]
+pub struct UsergroupUpdate {
+    pub name: Option<String>,
+    pub handle: Option<String>,
+    pub description: Option<String>,
+    pub channels: Option<Vec<conversation::Id>>,
+    pub team_id: Option<team::Id>,
+}
+
+#[derive(Clone, Default)]
+pub struct ListOptions {
+    pub include_users: bool,
+    pub include_disabled: bool,
+    pub include_count: bool,
+    pub team_id: Option<team::Id>,
+}
+
+// CREATE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CreateResponse {
+    Ok { usergroup: Box<Usergroup> },
+    Error { error: String },
+}
+
+// UPDATE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum UpdateResponse {
+    Ok { usergroup: Box<Usergroup> },
+    Error { error: String },
+}
+
+// DISABLE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DisableResponse {
+    Ok { usergroup: Box<Usergroup> },
+    Error { error: String },
+}
+
+// ENABLE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum EnableResponse {
+    Ok { usergroup: Box<Usergroup> },
+    Error { error: String },
+}
+
+// LIST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ListResponse {
+    Ok { usergroups: Vec<Usergroup> },
+    Error { error: String },
+}
+
+// USERS LIST
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum UsersListResponse {
+    Ok { users: Vec<user::Id> },
+    Error { error: String },
+}
+
+// USERS UPDATE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum UsersUpdateResponse {
+    Ok { usergroup: Box<Usergroup> },
+    Error { error: String },
+}
+
+impl Id {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl NewUsergroup {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            handle: None,
+            description: None,
+            channels: Vec::new(),
+            team_id: None,
+        }
+    }
+
+    /// The mention handle, without the `@`
+    pub fn set_handle(self, handle: String) -> Self {
+        Self {
+            handle: Some(handle),
+            ..self
+        }
+    }
+
+    pub fn set_description(self, description: String) -> Self {
+        Self {
+            description: Some(description),
+            ..self
+        }
+    }
+
+    pub fn set_channels(self, channels: Vec<conversation::Id>) -> Self {
+        Self { channels, ..self }
+    }
+
+    /// Needed when creating with an org level token
+    pub fn set_team_id(self, team_id: team::Id) -> Self {
+        Self {
+            team_id: Some(team_id),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, String)> {
+        let mut opts = vec![
+            ("name", self.name.clone()),
+            ("include_count", true.to_string()),
+        ];
+
+        if let Some(handle) = &self.handle {
+            opts.push(("handle", handle.clone()))
+        }
+
+        if let Some(description) = &self.description {
+            opts.push(("description", description.clone()))
+        }
+
+        if !self.channels.is_empty() {
+            opts.push(("channels", join_channels(&self.channels)))
+        }
+
+        if let Some(team_id) = &self.team_id {
+            opts.push(("team_id", team_id.0.clone()))
+        }
+
+        opts
+    }
+}
+
+impl UsergroupUpdate {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_name(self, name: String) -> Self {
+        Self {
+            name: Some(name),
+            ..self
+        }
+    }
+
+    pub fn set_handle(self, handle: String) -> Self {
+        Self {
+            handle: Some(handle),
+            ..self
+        }
+    }
+
+    pub fn set_description(self, description: String) -> Self {
+        Self {
+            description: Some(description),
+            ..self
+        }
+    }
+
+    /// Replaces the group's default channels
+    pub fn set_channels(self, channels: Vec<conversation::Id>) -> Self {
+        Self {
+            channels: Some(channels),
+            ..self
+        }
+    }
+
+    pub fn set_team_id(self, team_id: team::Id) -> Self {
+        Self {
+            team_id: Some(team_id),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, String)> {
+        let mut opts = vec![("include_count", true.to_string())];
+
+        if let Some(name) = &self.name {
+            opts.push(("name", name.clone()))
+        }
+
+        if let Some(handle) = &self.handle {
+            opts.push(("handle", handle.clone()))
+        }
+
+        if let Some(description) = &self.description {
+            opts.push(("description", description.clone()))
+        }
+
+        if let Some(channels) = &self.channels {
+            opts.push(("channels", join_channels(channels)))
+        }
+
+        if let Some(team_id) = &self.team_id {
+            opts.push(("team_id", team_id.0.clone()))
+        }
+
+        opts
+    }
+}
+
+impl ListOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn include_users(self, include: bool) -> Self {
+        Self {
+            include_users: include,
+            ..self
+        }
+    }
+
+    pub fn include_disabled(self, include: bool) -> Self {
+        Self {
+            include_disabled: include,
+            ..self
+        }
+    }
+
+    pub fn include_count(self, include: bool) -> Self {
+        Self {
+            include_count: include,
+            ..self
+        }
+    }
+
+    pub fn set_team_id(self, team_id: team::Id) -> Self {
+        Self {
+            team_id: Some(team_id),
+            ..self
+        }
+    }
+
+    pub fn query_params(&self) -> Vec<(&str, String)> {
+        let mut opts = vec![
+            ("include_users", self.include_users.to_string()),
+            ("include_disabled", self.include_disabled.to_string()),
+            ("include_count", self.include_count.to_string()),
+        ];
+
+        if let Some(team_id) = &self.team_id {
+            opts.push(("team_id", team_id.0.clone()))
+        }
+
+        opts
+    }
+}
+
+fn join_channels(channels: &[conversation::Id]) -> String {
+    channels
+        .iter()
+        .map(|channel| channel.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Slack sends `user_count` as a number from some methods and as a string from
+/// others.
+fn optional_count<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Count {
+        Number(u32),
+        String(String),
+    }
+
+    match Option::<Count>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Count::Number(count)) => Ok(Some(count)),
+        Some(Count::String(count)) => count.parse().map(Some).map_err(de::Error::custom),
+    }
+}
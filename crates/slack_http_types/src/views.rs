@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    blocks::{Block, Text},
+    interactivity::State,
+    team,
+};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Id(pub String);
+
+/// Short lived ID Slack sends along with interactions and slash commands.
+/// Needed to open a modal and expires after 3 seconds.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct TriggerId(pub String);
+
+/// A modal to open, push or update to
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename = "modal")]
+pub struct Modal {
+    /// Has to be plain text and at most 24 characters
+    pub title: Text,
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    pub clear_on_close: bool,
+    pub notify_on_close: bool,
+}
+
+/// A user's App Home tab
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename = "home")]
+pub struct Home {
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+/// A view as Slack has it, returned when one is opened, pushed, updated or
+/// published
+#[derive(Debug, Deserialize)]
+pub struct View {
+    pub id: Id,
+    pub team_id: team::Id,
+    #[serde(rename = "type")]
+    pub kind: Kind,
+    pub blocks: Vec<Block>,
+    pub title: Option<Text>,
+    #[serde(default)]
+    pub private_metadata: String,
+    #[serde(default)]
+    pub callback_id: String,
+    #[serde(default)]
+    pub external_id: String,
+    /// Pass this back when updating the view so it doesn't overwrite changes
+    /// made since it was read
+    pub hash: String,
+    /// Current values of the view's input elements
+    #[serde(default)]
+    pub state: State,
+    pub previous_view_id: Option<Id>,
+    pub root_view_id: Option<Id>,
+    pub app_id: Option<String>,
+    pub bot_id: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    Modal,
+    Home,
+    /// A view type this crate doesn't know about, e.g. `workflow_step`
+    #[serde(other)]
+    Unknown,
+}
+
+// OPEN
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum OpenResponse {
+    Ok { view: Box<View> },
+    Error { error: String },
+}
+
+// PUSH
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PushResponse {
+    Ok { view: Box<View> },
+    Error { error: String },
+}
+
+// UPDATE
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum UpdateResponse {
+    Ok { view: Box<View> },
+    Error { error: String },
+}
+
+// PUBLISH
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PublishResponse {
+    Ok { view: Box<View> },
+    Error { error: String },
+}
+
+impl Id {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl TriggerId {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Modal {
+    pub fn new(title: String, blocks: Vec<Block>) -> Self {
+        Self {
+            title: Text::plain(title),
+            blocks,
+            submit: None,
+            close: None,
+            private_metadata: None,
+            callback_id: None,
+            external_id: None,
+            clear_on_close: false,
+            notify_on_close: false,
+        }
+    }
+
+    /// Label of the submit button. Needed when the modal has input blocks.
+    pub fn set_submit(self, submit: String) -> Self {
+        Self {
+            submit: Some(Text::plain(submit)),
+            ..self
+        }
+    }
+
+    pub fn set_close(self, close: String) -> Self {
+        Self {
+            close: Some(Text::plain(close)),
+            ..self
+        }
+    }
+
+    /// Sent back with every interaction on the view. At most 3000 characters.
+    pub fn set_private_metadata(self, private_metadata: String) -> Self {
+        Self {
+            private_metadata: Some(private_metadata),
+            ..self
+        }
+    }
+
+    pub fn set_callback_id(self, callback_id: String) -> Self {
+        Self {
+            callback_id: Some(callback_id),
+            ..self
+        }
+    }
+
+    /// An ID unique across the team that the view can be updated by
+    pub fn set_external_id(self, external_id: String) -> Self {
+        Self {
+            external_id: Some(external_id),
+            ..self
+        }
+    }
+
+    /// Closes every view in the stack when this one is closed
+    pub fn set_clear_on_close(self, clear_on_close: bool) -> Self {
+        Self {
+            clear_on_close,
+            ..self
+        }
+    }
+
+    /// Sends a `view_closed` payload when the user closes the view
+    pub fn set_notify_on_close(self, notify_on_close: bool) -> Self {
+        Self {
+            notify_on_close,
+            ..self
+        }
+    }
+}
+
+impl Home {
+    pub fn new(blocks: Vec<Block>) -> Self {
+        Self {
+            blocks,
+            private_metadata: None,
+            callback_id: None,
+            external_id: None,
+        }
+    }
+
+    pub fn set_private_metadata(self, private_metadata: String) -> Self {
+        Self {
+            private_metadata: Some(private_metadata),
+            ..self
+        }
+    }
+
+    pub fn set_callback_id(self, callback_id: String) -> Self {
+        Self {
+            callback_id: Some(callback_id),
+            ..self
+        }
+    }
+
+    pub fn set_external_id(self, external_id: String) -> Self {
+        Self {
+            external_id: Some(external_id),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::{
+    blocks::Block,
+    chat::{Attachment, MessageOptions},
+};
+
+/// A message posted to an incoming webhook. The webhook decides the channel.
+#[derive(Clone, Debug, Serialize)]
+pub struct WebhookMessage {
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    /// Webhooks of apps only honor `username`, `icon_emoji` and `icon_url`
+    /// when the app has the `chat:write.customize` scope
+    #[serde(flatten)]
+    pub opts: MessageOptions,
+}
+
+impl WebhookMessage {
+    pub fn new(text: String) -> Self {
+        Self {
+            text,
+            blocks: Vec::new(),
+            attachments: Vec::new(),
+            thread_ts: None,
+            opts: MessageOptions::default(),
+        }
+    }
+
+    /// When there are blocks, the text is only used for notifications
+    pub fn set_blocks(self, blocks: Vec<Block>) -> Self {
+        Self { blocks, ..self }
+    }
+
+    pub fn set_attachments(self, attachments: Vec<Attachment>) -> Self {
+        Self {
+            attachments,
+            ..self
+        }
+    }
+
+    pub fn set_thread_ts(self, thread_ts: &OffsetDateTime) -> Self {
+        Self {
+            thread_ts: Some(crate::offset_date_time_to_unix_ts_with_nano(thread_ts)),
+            ..self
+        }
+    }
+
+    pub fn set_opts(self, opts: MessageOptions) -> Self {
+        Self { opts, ..self }
+    }
+}